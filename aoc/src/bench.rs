@@ -0,0 +1,100 @@
+use std::time::Duration;
+use utils::Options;
+
+use crate::days::solve_day;
+
+/// Timing summary for one stage (parse, part 1 or part 2) of a day.
+pub struct Stats {
+    pub day: u32,
+    pub stage: &'static str,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    fn from_samples(day: u32, stage: &'static str, samples: &mut [Duration]) -> Stats {
+        samples.sort();
+        let runs = samples.len();
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+        Stats {
+            day,
+            stage,
+            runs,
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / runs as u32,
+        }
+    }
+
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"day\": {}, \"stage\": \"{}\", \"runs\": {}, \"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}}}",
+            self.day,
+            self.stage,
+            self.runs,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.mean.as_nanos()
+        )
+    }
+}
+
+/// Solves `input` `runs` times, timing parsing and each of `parts` separately.
+pub fn bench_day(
+    day: u32,
+    input: &str,
+    parts: &[u32],
+    runs: usize,
+    options: &Options,
+) -> utils::Result<Vec<Stats>> {
+    let mut parse_samples: Vec<Duration> = vec![];
+    let mut part_samples: Vec<Vec<Duration>> = vec![vec![]; parts.len()];
+
+    for _ in 0..runs {
+        let solved = solve_day(day, input, parts, options)?;
+        parse_samples.push(solved.parse_time);
+        for (samples, time) in part_samples.iter_mut().zip(solved.part_times) {
+            samples.push(time);
+        }
+    }
+
+    let mut stats = vec![Stats::from_samples(day, "parse", &mut parse_samples)];
+    for (part, samples) in parts.iter().zip(part_samples.iter_mut()) {
+        let stage = match part {
+            1 => "part1",
+            _ => "part2",
+        };
+        stats.push(Stats::from_samples(day, stage, samples));
+    }
+    Ok(stats)
+}
+
+pub fn print_table(stats: &[Stats]) {
+    println!(
+        "{:<4} {:<6} {:>5} {:>14} {:>14} {:>14}",
+        "Day", "Stage", "Runs", "Min", "Median", "Mean"
+    );
+    for x in stats {
+        println!(
+            "{:<4} {:<6} {:>5} {:>14} {:>14} {:>14}",
+            x.day,
+            x.stage,
+            x.runs,
+            format!("{:.3?}", x.min),
+            format!("{:.3?}", x.median),
+            format!("{:.3?}", x.mean)
+        );
+    }
+}
+
+/// Renders the results as a JSON array, one object per day and stage.
+pub fn to_json(stats: &[Stats]) -> String {
+    let rows: Vec<String> = stats.iter().map(|x| format!("  {}", x.to_json())).collect();
+    format!("[\n{}\n]\n", rows.join(",\n"))
+}
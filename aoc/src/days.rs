@@ -0,0 +1,80 @@
+use std::time::{Duration, Instant};
+use utils::{Answer, Options, ReportFormat, Result, Solution};
+
+pub const DAYS: u32 = 10;
+
+/// Answers for the requested parts, with the wall time each stage took.
+pub struct Solved {
+    pub answers: Vec<Answer>,
+    pub parse_time: Duration,
+    pub part_times: Vec<Duration>,
+    pub warnings: Vec<String>,
+}
+
+fn solve<S: Solution>(input: &str, parts: &[u32], options: &Options) -> Result<Solved> {
+    let start = Instant::now();
+    let parsed = S::parse_with(input, options)?;
+    let parse_time = start.elapsed();
+    let warnings = S::warnings(&parsed);
+
+    let mut answers = vec![];
+    let mut part_times = vec![];
+    for part in parts {
+        let start = Instant::now();
+        let answer = match part {
+            1 => S::part1(&parsed),
+            _ => S::part2(&parsed),
+        };
+        part_times.push(start.elapsed());
+        answers.push(answer);
+    }
+    Ok(Solved {
+        answers,
+        parse_time,
+        part_times,
+        warnings,
+    })
+}
+
+fn report<S: Solution>(
+    input: &str,
+    format: ReportFormat,
+    options: &Options,
+) -> Result<Option<String>> {
+    let parsed = S::parse_with(input, options)?;
+    Ok(S::report(&parsed, format))
+}
+
+/// Calls the generic function `f` with the given day's solution type.
+macro_rules! with_day {
+    ($day:expr, $f:ident($($arg:expr),*)) => {
+        match $day {
+            1 => $f::<day1::Day1>($($arg),*),
+            2 => $f::<day2::Day2>($($arg),*),
+            3 => $f::<day3::Day3>($($arg),*),
+            4 => $f::<day4::Day4>($($arg),*),
+            5 => $f::<day5::Day5>($($arg),*),
+            6 => $f::<day6::Day6>($($arg),*),
+            7 => $f::<day7::Day7>($($arg),*),
+            8 => $f::<day8::Day8>($($arg),*),
+            9 => $f::<day9::Day9>($($arg),*),
+            10 => $f::<day10::Day10>($($arg),*),
+            day => panic!("Unknown day {}", day),
+        }
+    };
+}
+
+/// Parses `input` as the given day's puzzle and solves each of `parts`.
+pub fn solve_day(day: u32, input: &str, parts: &[u32], options: &Options) -> Result<Solved> {
+    with_day!(day, solve(input, parts, options))
+}
+
+/// Parses `input` as the given day's puzzle and renders its report, if any.
+pub fn report_day(
+    day: u32,
+    input: &str,
+    format: ReportFormat,
+    options: &Options,
+) -> Result<Option<String>> {
+    with_day!(day, report(input, format, options))
+}
@@ -0,0 +1,205 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use utils::{Answer, InputLocator, InputSource, Options, ReportFormat};
+
+mod bench;
+mod days;
+mod verify;
+
+use days::{report_day, solve_day, DAYS};
+
+fn usage() -> ! {
+    eprintln!(
+        "Usage: aoc run [--day N] [--part 1|2] [--input NAME | --file PATH|-] [--inputs DIR]"
+    );
+    eprintln!("       aoc verify [--day N] [--inputs DIR]");
+    eprintln!("       aoc report --day N [--input NAME | --file PATH|-] [--format text|csv|json]");
+    eprintln!("       aoc bench [--day N] [--part 1|2] [--input NAME] [--runs N] [--json PATH|-]");
+    eprintln!("run, report and bench also take [--option KEY=VALUE]... to pass settings to a day.");
+    process::exit(2);
+}
+
+struct Args {
+    day: Option<u32>,
+    part: Option<u32>,
+    source: InputSource,
+    inputs_dir: Option<PathBuf>,
+    runs: usize,
+    json: Option<String>,
+    format: ReportFormat,
+    options: Options,
+}
+
+fn parse_number(value: &str, max: u32) -> u32 {
+    match value.parse::<u32>() {
+        Ok(x) if (1..=max).contains(&x) => x,
+        _ => usage(),
+    }
+}
+
+fn parse_args(args: &[String]) -> Args {
+    let mut parsed = Args {
+        day: None,
+        part: None,
+        source: InputSource::Named("input.txt".to_string()),
+        inputs_dir: None,
+        runs: 10,
+        json: None,
+        format: ReportFormat::Text,
+        options: Options::new(),
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = match iter.next() {
+            Some(x) => x,
+            None => usage(),
+        };
+        match arg.as_str() {
+            "--day" | "-d" => parsed.day = Some(parse_number(value, DAYS)),
+            "--part" | "-p" => parsed.part = Some(parse_number(value, 2)),
+            "--input" | "-i" => parsed.source = InputSource::Named(value.to_string()),
+            "--file" | "-f" => {
+                parsed.source = match value.as_str() {
+                    "-" => InputSource::Stdin,
+                    _ => InputSource::File(PathBuf::from(value)),
+                }
+            }
+            "--inputs" => parsed.inputs_dir = Some(PathBuf::from(value)),
+            "--runs" | "-n" => parsed.runs = parse_number(value, u32::MAX) as usize,
+            "--json" => parsed.json = Some(value.to_string()),
+            "--format" => parsed.format = value.parse().unwrap_or_else(|_| usage()),
+            "--option" | "-o" => parsed.options.set_pair(value).unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                usage()
+            }),
+            _ => usage(),
+        }
+    }
+    parsed
+}
+
+fn load_input(locator: &InputLocator, day: u32, source: &InputSource) -> String {
+    locator.load(day, source).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    })
+}
+
+/// Solves and prints one day. Returns whether no part failed.
+fn run_day(day: u32, input: &str, parts: &[u32], options: &Options) -> bool {
+    let solved = solve_day(day, input, parts, options).unwrap_or_else(|e| {
+        eprintln!("Failed to parse input: {}", e);
+        process::exit(1);
+    });
+
+    for warning in &solved.warnings {
+        eprintln!("Warning: {}", warning);
+    }
+    println!("Parse: ({:.3?})", solved.parse_time);
+    let mut ok = true;
+    for ((part, answer), time) in parts.iter().zip(solved.answers).zip(solved.part_times) {
+        println!("Part {}: {} ({:.3?})", part, answer, time);
+        ok &= !matches!(answer, Answer::Failed(_));
+    }
+    ok
+}
+
+fn bench(args: Args) {
+    let locator = InputLocator::resolve(args.inputs_dir.as_deref());
+    let days: Vec<u32> = match args.day {
+        Some(x) => vec![x],
+        None => (1..=DAYS).collect(),
+    };
+    let parts: Vec<u32> = match args.part {
+        Some(x) => vec![x],
+        None => vec![1, 2],
+    };
+
+    let mut stats = vec![];
+    for day in days {
+        let input = load_input(&locator, day, &args.source);
+        let day_stats = bench::bench_day(day, &input, &parts, args.runs, &args.options)
+            .unwrap_or_else(|e| {
+                eprintln!("Failed to parse input: {}", e);
+                process::exit(1);
+            });
+        stats.extend(day_stats);
+    }
+
+    match args.json.as_deref() {
+        Some("-") => print!("{}", bench::to_json(&stats)),
+        Some(path) => {
+            bench::print_table(&stats);
+            fs::write(path, bench::to_json(&stats)).unwrap_or_else(|e| {
+                eprintln!("Failed to write {}: {}", path, e);
+                process::exit(1);
+            });
+        }
+        None => bench::print_table(&stats),
+    }
+}
+
+fn run(args: Args) {
+    let days: Vec<u32> = match (args.day, &args.source) {
+        (Some(x), _) => vec![x],
+        (None, InputSource::Named(_)) => (1..=DAYS).collect(),
+        // A single file or stdin only makes sense for one day
+        (None, _) => usage(),
+    };
+    let locator = InputLocator::resolve(args.inputs_dir.as_deref());
+    let parts: Vec<u32> = match args.part {
+        Some(x) => vec![x],
+        None => vec![1, 2],
+    };
+
+    let mut ok = true;
+    for day in days {
+        println!("--- Day {} ---", day);
+        let input = load_input(&locator, day, &args.source);
+        ok &= run_day(day, &input, &parts, &args.options);
+    }
+    if !ok {
+        process::exit(1);
+    }
+}
+
+fn report(args: Args) {
+    let day = args.day.unwrap_or_else(|| usage());
+    let locator = InputLocator::resolve(args.inputs_dir.as_deref());
+    let input = load_input(&locator, day, &args.source);
+    let report = report_day(day, &input, args.format, &args.options).unwrap_or_else(|e| {
+        eprintln!("Failed to parse input: {}", e);
+        process::exit(1);
+    });
+    match report {
+        Some(x) => print!("{}", x),
+        None => {
+            eprintln!("Day {} has no report", day);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(|x| x.as_str()) {
+        Some("run") => run(parse_args(&args[1..])),
+        Some("bench") => bench(parse_args(&args[1..])),
+        Some("report") => report(parse_args(&args[1..])),
+        Some("verify") => {
+            let args = parse_args(&args[1..]);
+            let locator = InputLocator::resolve(args.inputs_dir.as_deref());
+            let days: Vec<u32> = match args.day {
+                Some(x) => vec![x],
+                None => (1..=DAYS).collect(),
+            };
+            if !verify::verify(&locator, &days) {
+                process::exit(1);
+            }
+        }
+        _ => usage(),
+    }
+}
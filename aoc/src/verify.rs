@@ -0,0 +1,103 @@
+use utils::{Answers, InputError, InputLocator, InputSource, Options, ANSWERS_FILE};
+
+use crate::days::solve_day;
+
+fn row(day: &str, input: &str, part: &str, expected: &str, actual: &str, result: &str) {
+    println!(
+        "{:<4} {:<10} {:<5} {:<16} {:<16} {}",
+        day, input, part, expected, actual, result
+    );
+}
+
+fn load_answers(locator: &InputLocator, day: u32) -> Result<Option<Answers>, String> {
+    let source = InputSource::Named(ANSWERS_FILE.to_string());
+    match locator.load(day, &source) {
+        Ok(text) => Answers::parse(day, &text)
+            .map(Some)
+            .map_err(|e| e.to_string()),
+        Err(InputError::NotFound(_)) => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Runs every day against its answers file, printing a table. Returns whether all passed.
+pub fn verify(locator: &InputLocator, days: &[u32]) -> bool {
+    let mut passed = 0;
+    let mut failed = 0;
+
+    row("Day", "Input", "Part", "Expected", "Actual", "Result");
+    for day in days {
+        let day = *day;
+        let answers = match load_answers(locator, day) {
+            Ok(Some(x)) => x,
+            Ok(None) => continue,
+            Err(e) => {
+                row(
+                    &day.to_string(),
+                    ANSWERS_FILE,
+                    "-",
+                    "-",
+                    "-",
+                    &format!("FAIL ({})", e),
+                );
+                failed += 1;
+                continue;
+            }
+        };
+
+        for (stem, expected) in answers.inputs() {
+            let name = format!("{}.txt", stem);
+            let parts: Vec<u32> = [1, 2]
+                .into_iter()
+                .filter(|x| expected.part(*x).is_some())
+                .collect();
+
+            let solved = locator
+                .load(day, &InputSource::Named(name.clone()))
+                .map_err(|e| e.to_string())
+                .and_then(|input| {
+                    solve_day(day, &input, &parts, &Options::new()).map_err(|e| e.to_string())
+                });
+            let answers = match solved {
+                Ok(x) => x.answers,
+                Err(e) => {
+                    row(
+                        &day.to_string(),
+                        &name,
+                        "-",
+                        "-",
+                        "-",
+                        &format!("FAIL ({})", e),
+                    );
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            for (part, answer) in parts.iter().zip(answers) {
+                let expected_text = expected.part(*part).unwrap_or("-");
+                let result = match expected.matches(*part, &answer) {
+                    Some(true) => {
+                        passed += 1;
+                        "pass"
+                    }
+                    _ => {
+                        failed += 1;
+                        "FAIL"
+                    }
+                };
+                row(
+                    &day.to_string(),
+                    &name,
+                    &part.to_string(),
+                    expected_text,
+                    &answer.to_string(),
+                    result,
+                );
+            }
+        }
+    }
+
+    println!("{} passed, {} failed", passed, failed);
+    failed == 0
+}
@@ -0,0 +1,436 @@
+use std::fs;
+use utils::parse::{self, Line};
+use utils::scanner::Match;
+use utils::{Answer, Options, ReportFormat, Result, Scanner, Solution};
+
+const DAY: u32 = 1;
+
+// Number words for 1 to 9 in each built in language
+const ENGLISH: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const GERMAN: [&str; 9] = [
+    "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+const FRENCH: [&str; 9] = [
+    "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+const SPANISH: [&str; 9] = [
+    "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+];
+
+/// Which spelled out words count as digits, on top of the digits themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Vocabulary {
+    /// Digits only.
+    Numeric,
+    /// "one" to "nine", as in the puzzle.
+    English,
+    /// "eins" to "neun".
+    German,
+    /// "un" to "neuf".
+    French,
+    /// "uno" to "nueve".
+    Spanish,
+    /// Words and the digit each stands for, e.g. read from a file.
+    Custom(Vec<(String, u32)>),
+}
+
+impl Vocabulary {
+    /// Reads the `words` option: `numeric`, a language (`english`, `german`,
+    /// `french` or `spanish`), or the path of a word list file.
+    pub fn from_option(value: &str) -> std::result::Result<Vocabulary, String> {
+        match value {
+            "numeric" => Ok(Vocabulary::Numeric),
+            "english" => Ok(Vocabulary::English),
+            "german" => Ok(Vocabulary::German),
+            "french" => Ok(Vocabulary::French),
+            "spanish" => Ok(Vocabulary::Spanish),
+            path => {
+                let text = fs::read_to_string(path)
+                    .map_err(|e| format!("failed to read {}: {}", path, e))?;
+                Vocabulary::parse_words(&text).map(Vocabulary::Custom)
+            }
+        }
+    }
+
+    /// Parses a word list with one `word digit` pair per line. Blank lines
+    /// and `#` comments are skipped.
+    pub fn parse_words(text: &str) -> std::result::Result<Vec<(String, u32)>, String> {
+        let mut words: Vec<(String, u32)> = vec![];
+        for (i, line) in text.lines().enumerate() {
+            let content = match line.split_once('#') {
+                Some((x, _)) => x,
+                None => line,
+            }
+            .trim();
+            if content.is_empty() {
+                continue;
+            }
+            let value = match content.split_whitespace().collect::<Vec<&str>>()[..] {
+                [word, digit] => match digit.parse::<u32>() {
+                    Ok(x) if x < 10 => (word.to_string(), x),
+                    _ => {
+                        return Err(format!(
+                            "line {}: expected a digit, found {:?}",
+                            i + 1,
+                            digit
+                        ))
+                    }
+                },
+                _ => return Err(format!("line {}: expected \"word digit\"", i + 1)),
+            };
+            words.push(value);
+        }
+        Ok(words)
+    }
+
+    pub fn words(&self) -> Vec<(String, u32)> {
+        let table = match self {
+            Vocabulary::Numeric => return vec![],
+            Vocabulary::English => ENGLISH,
+            Vocabulary::German => GERMAN,
+            Vocabulary::French => FRENCH,
+            Vocabulary::Spanish => SPANISH,
+            Vocabulary::Custom(words) => return words.clone(),
+        };
+        table.iter().map(|x| x.to_string()).zip(1..).collect()
+    }
+
+    /// Matches the digits 0 to 9 and every word of the vocabulary, the words
+    /// in any case if `ignore_case` is set.
+    pub fn scanner(&self, ignore_case: bool) -> Scanner<u32> {
+        let patterns = (0..10).map(|x| (x.to_string(), x)).chain(self.words());
+        match ignore_case {
+            true => Scanner::new_ignore_case(patterns),
+            false => Scanner::new(patterns),
+        }
+    }
+}
+
+/// How lines without any digit are treated. `Skip` and `Zero` give the same
+/// totals; the report tells them apart, showing no value for a skipped line
+/// and 0 for a zeroed one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoDigits {
+    /// Fail the part, pointing at the first such line.
+    Error,
+    /// Leave the line out of the total.
+    Skip,
+    /// Count the line as 0.
+    Zero,
+}
+
+impl NoDigits {
+    /// Reads the `no_digits` option: `error`, `skip` or `zero`.
+    pub fn from_option(value: &str) -> std::result::Result<NoDigits, String> {
+        match value {
+            "error" => Ok(NoDigits::Error),
+            "skip" => Ok(NoDigits::Skip),
+            "zero" => Ok(NoDigits::Zero),
+            _ => Err("expected error, skip or zero".to_string()),
+        }
+    }
+}
+
+/// The first and last digit found on a line, if it has any.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineValue {
+    /// Line number, from 1.
+    pub line: usize,
+    pub first: Option<u32>,
+    pub last: Option<u32>,
+}
+
+impl LineValue {
+    /// The calibration value, the first and last digit read as a number.
+    pub fn value(&self) -> Option<u32> {
+        Some(self.first? * 10 + self.last?)
+    }
+}
+
+/// The calibration document, with the vocabulary part 2 reads it with.
+pub struct Calibration {
+    lines: Vec<String>,
+    vocabulary: Vocabulary,
+    ignore_case: bool,
+    no_digits: NoDigits,
+}
+
+impl Calibration {
+    /// What every line reads as with `vocabulary`.
+    pub fn read(&self, vocabulary: &Vocabulary) -> Vec<LineValue> {
+        let scanner = vocabulary.scanner(self.ignore_case);
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| parse_line(&scanner, i + 1, line))
+            .collect()
+    }
+
+    /// What a line adds to the total: its value, 0 for a line without
+    /// digits under `zero`, and nothing otherwise.
+    pub fn counted(&self, x: &LineValue) -> Option<u32> {
+        match (x.value(), self.no_digits) {
+            (Some(value), _) => Some(value),
+            (None, NoDigits::Zero) => Some(0),
+            (None, NoDigits::Skip | NoDigits::Error) => None,
+        }
+    }
+
+    /// Sums every line's calibration value when read with `vocabulary`,
+    /// treating lines without digits as the `no_digits` option says.
+    pub fn total(&self, vocabulary: &Vocabulary) -> Result<u32> {
+        let mut total = 0;
+        for x in self.read(vocabulary) {
+            total += match (self.counted(&x), self.no_digits) {
+                (Some(value), _) => value,
+                (None, NoDigits::Skip | NoDigits::Zero) => continue,
+                (None, NoDigits::Error) => {
+                    let line = Line {
+                        day: DAY,
+                        number: x.line,
+                        text: &self.lines[x.line - 1],
+                    };
+                    return Err(line.error(line.text, "expected a line with a digit"));
+                }
+            };
+        }
+        Ok(total)
+    }
+
+    /// Both parts' readings of every line, side by side.
+    fn report(&self, format: ReportFormat) -> String {
+        let digits = self.read(&Vocabulary::Numeric);
+        let words = self.read(&self.vocabulary);
+        let show = |x: Option<u32>| x.map_or("-".to_string(), |x| x.to_string());
+        let json = |x: Option<u32>| x.map_or("null".to_string(), |x| x.to_string());
+
+        let mut out = String::new();
+        match format {
+            ReportFormat::Text => {
+                out.push_str(&format!("{:<6} {:<20}  {}\n", "", "Part 1", "Part 2"));
+                out.push_str(&format!(
+                    "{:<6} {:>6} {:>6} {:>6}  {:>6} {:>6} {:>6}\n",
+                    "Line", "First", "Last", "Value", "First", "Last", "Value"
+                ));
+                for (x, y) in digits.iter().zip(&words) {
+                    out.push_str(&format!(
+                        "{:<6} {:>6} {:>6} {:>6}  {:>6} {:>6} {:>6}\n",
+                        x.line,
+                        show(x.first),
+                        show(x.last),
+                        show(self.counted(x)),
+                        show(y.first),
+                        show(y.last),
+                        show(self.counted(y))
+                    ));
+                }
+            }
+            ReportFormat::Csv => {
+                out.push_str(
+                    "line,part1_first,part1_last,part1_value,part2_first,part2_last,part2_value\n",
+                );
+                for (x, y) in digits.iter().zip(&words) {
+                    let cells: Vec<String> = [
+                        x.first,
+                        x.last,
+                        self.counted(x),
+                        y.first,
+                        y.last,
+                        self.counted(y),
+                    ]
+                    .into_iter()
+                    .map(|x| x.map_or(String::new(), |x| x.to_string()))
+                    .collect();
+                    out.push_str(&format!("{},{}\n", x.line, cells.join(",")));
+                }
+            }
+            ReportFormat::Json => {
+                let rows: Vec<String> = digits
+                    .iter()
+                    .zip(&words)
+                    .map(|(x, y)| {
+                        format!(
+                            "  {{\"line\": {}, \"part1\": {{\"first\": {}, \"last\": {}, \"value\": {}}}, \"part2\": {{\"first\": {}, \"last\": {}, \"value\": {}}}}}",
+                            x.line,
+                            json(x.first),
+                            json(x.last),
+                            json(self.counted(x)),
+                            json(y.first),
+                            json(y.last),
+                            json(self.counted(y))
+                        )
+                    })
+                    .collect();
+                out.push_str(&format!("[\n{}\n]\n", rows.join(",\n")));
+            }
+        }
+        out
+    }
+}
+
+fn parse_line(scanner: &Scanner<u32>, number: usize, line: &str) -> LineValue {
+    // Words may overlap, as in "eightwo", so first and last go by start. The
+    // starts are byte offsets, so lines with non-ASCII letters order correctly
+    let matches: Vec<Match<u32>> = scanner.find_iter(line).collect();
+    LineValue {
+        line: number,
+        first: matches.iter().min_by_key(|x| x.start).map(|x| *x.value),
+        last: matches.iter().max_by_key(|x| x.start).map(|x| *x.value),
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Calibration;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Options::new())
+    }
+
+    fn parse_with(input: &str, options: &Options) -> Result<Self::Input> {
+        let vocabulary = match options.get("words") {
+            Some(x) => Vocabulary::from_option(x)
+                .map_err(|e| parse::invalid_option(DAY, "words", x, &e))?,
+            None => Vocabulary::English,
+        };
+        let ignore_case = match options.get("ignore_case") {
+            None | Some("false") => false,
+            Some("true") => true,
+            Some(x) => {
+                return Err(parse::invalid_option(
+                    DAY,
+                    "ignore_case",
+                    x,
+                    "expected true or false",
+                ))
+            }
+        };
+        let no_digits = match options.get("no_digits") {
+            Some(x) => NoDigits::from_option(x)
+                .map_err(|e| parse::invalid_option(DAY, "no_digits", x, &e))?,
+            None => NoDigits::Error,
+        };
+        Ok(Calibration {
+            lines: input.lines().map(|line| line.to_string()).collect(),
+            vocabulary,
+            ignore_case,
+            no_digits,
+        })
+    }
+
+    // A part that hits a line without digits under the error policy fails,
+    // naming the line it stopped on
+    fn part1(input: &Self::Input) -> Answer {
+        match input.total(&Vocabulary::Numeric) {
+            Ok(x) => x.into(),
+            Err(e) => Answer::Failed(e.to_string()),
+        }
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        match input.total(&input.vocabulary) {
+            Ok(x) => x.into(),
+            Err(e) => Answer::Failed(e.to_string()),
+        }
+    }
+
+    fn report(input: &Self::Input, format: ReportFormat) -> Option<String> {
+        Some(input.report(format))
+    }
+}
+
+utils::example_tests!(crate::Day1, 1, "test", "test2");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // What each line reads as under the given options, as (first, last)
+    fn read(input: &str, options: &[(&str, &str)]) -> Vec<(Option<u32>, Option<u32>)> {
+        let mut opts = Options::new();
+        for (key, value) in options {
+            opts.set(*key, *value);
+        }
+        let calibration = Day1::parse_with(input, &opts).unwrap();
+        calibration
+            .read(&calibration.vocabulary)
+            .iter()
+            .map(|x| (x.first, x.last))
+            .collect()
+    }
+
+    #[test]
+    fn german_ignoring_case() {
+        let options = [("words", "german"), ("ignore_case", "true")];
+        assert_eq!(
+            read("FÜNF\nzwEIns\nxSIEBENacht3", &options),
+            vec![(Some(5), Some(5)), (Some(2), Some(1)), (Some(7), Some(3))]
+        );
+    }
+
+    #[test]
+    fn case_sensitive_by_default() {
+        let options = [("words", "german")];
+        assert_eq!(
+            read("FÜNF\nzwEIns\nfünf", &options),
+            vec![(None, None), (None, None), (Some(5), Some(5))]
+        );
+    }
+
+    #[test]
+    fn overlapping_words() {
+        assert_eq!(
+            read("cinqune\nhuitrois", &[("words", "french")]),
+            vec![(Some(5), Some(1)), (Some(8), Some(3))]
+        );
+        assert_eq!(
+            read(
+                "dosiete\nUNOcho",
+                &[("words", "spanish"), ("ignore_case", "true")]
+            ),
+            vec![(Some(2), Some(7)), (Some(1), Some(8))]
+        );
+        assert_eq!(read("eightwo", &[]), vec![(Some(8), Some(2))]);
+    }
+
+    #[test]
+    fn non_ascii_letters_keep_order() {
+        // "ü" is two bytes, so the later words start at byte offsets past
+        // their char positions
+        assert_eq!(
+            read(
+                "fünfeins\neinsfünf\nfÜnf1",
+                &[("words", "german"), ("ignore_case", "true")]
+            ),
+            vec![(Some(5), Some(1)), (Some(1), Some(5)), (Some(5), Some(1))]
+        );
+    }
+
+    #[test]
+    fn no_digits_policies() {
+        let input = "1abc\n\nxyz";
+        let mut options = Options::new();
+        let calibration = Day1::parse_with(input, &options).unwrap();
+        assert!(matches!(Day1::part1(&calibration), Answer::Failed(_)));
+
+        for (policy, value) in [("skip", "-"), ("zero", "0")] {
+            options.set("no_digits", policy);
+            let calibration = Day1::parse_with(input, &options).unwrap();
+            assert_eq!(Day1::part1(&calibration), Answer::from(11_u32));
+            let report = calibration.report(ReportFormat::Text);
+            let row: Vec<&str> = report.lines().nth(3).unwrap().split_whitespace().collect();
+            assert_eq!(row, ["2", "-", "-", value, "-", "-", value]);
+        }
+    }
+
+    #[test]
+    fn custom_words() {
+        let words = Vocabulary::parse_words("# comment\nuno 1\n\nzwei 2 # inline\n").unwrap();
+        assert_eq!(words, vec![("uno".to_string(), 1), ("zwei".to_string(), 2)]);
+        assert!(Vocabulary::parse_words("uno").is_err());
+        assert!(Vocabulary::parse_words("uno 10").is_err());
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use utils::{parse, Answer, Direction, Grid, Point, Result, Solution};
+
+const DAY: u32 = 10;
+
+// | is a vertical pipe connecting north and south.
+// - is a horizontal pipe connecting east and west.
+// L is a 90-degree bend connecting north and east.
+// J is a 90-degree bend connecting north and west.
+// 7 is a 90-degree bend connecting south and west.
+// F is a 90-degree bend connecting south and east.
+// . is ground; there is no pipe in this tile.
+
+#[derive(Debug, Clone, Copy)]
+enum Tile {
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Ground,
+    Start,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ch = match self {
+            Tile::Vertical => '|',
+            Tile::Horizontal => '-',
+            Tile::NorthEast => 'L',
+            Tile::NorthWest => 'J',
+            Tile::SouthWest => '7',
+            Tile::SouthEast => 'F',
+            Tile::Ground => '.',
+            Tile::Start => 'S',
+        };
+        write!(f, "{}", ch)
+    }
+}
+
+pub struct Maze {
+    tile_array: Grid<Tile>,
+    start: Point,
+}
+
+fn get_tile(ch: char) -> Option<Tile> {
+    let tile = match ch {
+        '|' => Tile::Vertical,
+        '-' => Tile::Horizontal,
+        'L' => Tile::NorthEast,
+        'J' => Tile::NorthWest,
+        '7' => Tile::SouthWest,
+        'F' => Tile::SouthEast,
+        '.' => Tile::Ground,
+        'S' => Tile::Start,
+        _ => return None,
+    };
+    Some(tile)
+}
+
+fn get_generate_tile_array_start(data: &str) -> Result<Maze> {
+    let tile_array = Grid::parse(DAY, data, get_tile)?;
+    let start = tile_array
+        .find(|x| matches!(x, Tile::Start))
+        .ok_or_else(|| parse::unexpected_end(DAY, data, "a start tile"))?;
+    Ok(Maze { tile_array, start })
+}
+
+fn is_compatible(current_tile: &Tile, next_tile: &Tile, direction: &Direction) -> bool {
+    match current_tile {
+        Tile::Start => match next_tile {
+            Tile::Vertical => matches!(direction, Direction::North | Direction::South),
+            Tile::Horizontal => matches!(direction, Direction::East | Direction::West),
+            Tile::NorthEast => *direction == Direction::South || *direction == Direction::West,
+            Tile::NorthWest => *direction == Direction::South || *direction == Direction::East,
+            Tile::SouthWest => *direction == Direction::North || *direction == Direction::East,
+            Tile::SouthEast => *direction == Direction::North || *direction == Direction::West,
+            _ => true,
+        },
+        Tile::Vertical => match next_tile {
+            Tile::NorthEast => *direction == Direction::South,
+            Tile::NorthWest => *direction == Direction::South,
+            Tile::SouthWest => *direction == Direction::North,
+            Tile::SouthEast => *direction == Direction::North,
+            Tile::Horizontal => false,
+            _ => true,
+        },
+        Tile::Horizontal => match next_tile {
+            Tile::SouthWest => *direction == Direction::East,
+            Tile::SouthEast => *direction == Direction::West,
+            Tile::NorthEast => *direction == Direction::West,
+            Tile::NorthWest => *direction == Direction::East,
+            Tile::Vertical => false,
+            _ => true,
+        },
+        Tile::NorthEast => match next_tile {
+            Tile::Vertical => *direction == Direction::North,
+            Tile::Horizontal => *direction == Direction::East,
+            Tile::NorthEast => false,
+            Tile::NorthWest => *direction == Direction::East,
+            Tile::SouthEast => *direction == Direction::North,
+            _ => true,
+        },
+        Tile::NorthWest => match next_tile {
+            Tile::Vertical => *direction == Direction::North,
+            Tile::Horizontal => *direction == Direction::West,
+            Tile::NorthEast => *direction == Direction::West,
+            Tile::NorthWest => false,
+            _ => true,
+        },
+        Tile::SouthWest => match next_tile {
+            Tile::Vertical => *direction == Direction::South,
+            Tile::Horizontal => *direction == Direction::West,
+            Tile::SouthWest => false,
+            Tile::SouthEast => *direction == Direction::West,
+            _ => true,
+        },
+        Tile::SouthEast => match next_tile {
+            Tile::Vertical => *direction == Direction::South,
+            Tile::Horizontal => *direction == Direction::East,
+            Tile::SouthWest => *direction == Direction::East,
+            Tile::SouthEast => false,
+            _ => true,
+        },
+        _ => true,
+    }
+}
+
+fn recurse_path(
+    tile_array: &Grid<Tile>,
+    visited: &mut HashSet<Point>,
+    visited_nodes: &mut Vec<Point>,
+    current_node: Point,
+    start_node: Point,
+) -> bool {
+    // Check if current node is out of bounds
+    let token = match tile_array.get(current_node) {
+        Some(x) => x,
+        None => return false,
+    };
+    if let Tile::Ground = token {
+        return false;
+    }
+    //
+
+    if visited.get(&current_node).is_some() {
+        return current_node == start_node;
+    } else {
+        visited.insert(current_node);
+    }
+
+    let directions: Vec<Direction> = match token {
+        Tile::Vertical => {
+            vec![Direction::South, Direction::North]
+        }
+        Tile::Horizontal => {
+            vec![Direction::East, Direction::West]
+        }
+        Tile::NorthEast => {
+            vec![Direction::East, Direction::North]
+        }
+        Tile::NorthWest => {
+            vec![Direction::North, Direction::West]
+        }
+        Tile::SouthWest => {
+            vec![Direction::West, Direction::South]
+        }
+        Tile::SouthEast => {
+            vec![Direction::South, Direction::East]
+        }
+        Tile::Start => {
+            vec![
+                Direction::East,
+                Direction::West,
+                Direction::South,
+                Direction::North,
+            ]
+        }
+        _ => {
+            panic!("Unknown Token")
+        }
+    };
+
+    for direction in directions {
+        let new_node = current_node + direction;
+        let new_tile = match tile_array.get(new_node) {
+            Some(x) => x,
+            None => continue,
+        };
+        if !is_compatible(token, new_tile, &direction) {
+            continue;
+        }
+        visited_nodes.push(new_node);
+        if recurse_path(tile_array, visited, visited_nodes, new_node, start_node) {
+            return true;
+        } else {
+            visited_nodes.pop();
+        }
+    }
+    false
+}
+
+fn get_valid_path(tile_array: &Grid<Tile>, start: Point) -> (Vec<Point>, bool) {
+    let mut visited: HashSet<Point> = HashSet::new();
+    let mut visited_nodes: Vec<Point> = vec![];
+
+    let is_valid = recurse_path(tile_array, &mut visited, &mut visited_nodes, start, start);
+    (visited_nodes, is_valid)
+}
+
+fn part2(tile_array: &Grid<Tile>, path: &[Point], start: Point) -> i64 {
+    // Scan Line: only loop tiles are walls, and a wall is crossed whenever a
+    // tile connects north, so an L---7 run counts once and an L---J run twice.
+    let on_path: HashSet<Point> = path.iter().copied().collect();
+
+    // The path ends back on the start, so its first and second to last nodes
+    // are the start's neighbours and give the shape hidden under the S.
+    let north = start.step(Direction::North);
+    let start_north =
+        path.first() == Some(&north) || (path.len() >= 2 && path[path.len() - 2] == north);
+
+    let mut total = 0;
+    for (j, row) in tile_array.rows().enumerate() {
+        let mut valid_area = false;
+        for (i, current_tile) in row.iter().enumerate() {
+            if !on_path.contains(&Point::new(j as i64, i as i64)) {
+                if valid_area {
+                    total += 1;
+                }
+                continue;
+            }
+            let north_facing = match current_tile {
+                Tile::Vertical | Tile::NorthEast | Tile::NorthWest => true,
+                Tile::Start => start_north,
+                _ => false,
+            };
+            if north_facing {
+                valid_area = !valid_area;
+            }
+        }
+    }
+    total
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        get_generate_tile_array_start(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (visited_nodes, valid_path) = get_valid_path(&input.tile_array, input.start);
+        if !valid_path {
+            return Answer::Unsolved;
+        }
+        (visited_nodes.len() / 2).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (visited_nodes, valid_path) = get_valid_path(&input.tile_array, input.start);
+        if !valid_path {
+            return Answer::Unsolved;
+        }
+        part2(&input.tile_array, &visited_nodes, input.start).into()
+    }
+}
+
+utils::example_tests!(crate::Day10, 10);
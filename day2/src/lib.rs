@@ -0,0 +1,453 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use utils::parse::{self, Line};
+use utils::{Answer, Options, ReportFormat, Result, Solution};
+
+const DAY: u32 = 2;
+
+/// The cubes in the bag, by colour. Colours not listed have none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    cubes: BTreeMap<String, u32>,
+}
+
+impl Default for Bag {
+    /// The bag from the puzzle: 12 red, 13 green and 14 blue cubes.
+    fn default() -> Bag {
+        let mut bag = Bag::new();
+        bag.set("red", 12);
+        bag.set("green", 13);
+        bag.set("blue", 14);
+        bag
+    }
+}
+
+impl Bag {
+    /// An empty bag.
+    pub fn new() -> Bag {
+        Bag {
+            cubes: BTreeMap::new(),
+        }
+    }
+
+    pub fn set(&mut self, colour: &str, count: u32) {
+        self.cubes.insert(colour.to_string(), count);
+    }
+
+    pub fn get(&self, colour: &str) -> u32 {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+
+    /// Parses a bag written as `red:12,green:13,blue:14`.
+    pub fn parse(text: &str) -> std::result::Result<Bag, String> {
+        let mut bag = Bag::new();
+        for entry in text.split(',').map(|x| x.trim()).filter(|x| !x.is_empty()) {
+            let (colour, count) = entry
+                .split_once(':')
+                .ok_or_else(|| format!("expected colour:count, found {:?}", entry))?;
+            let colour = colour.trim();
+            if colour.is_empty() || !colour.chars().all(|x| x.is_ascii_alphabetic()) {
+                return Err(format!("expected a colour, found {:?}", colour));
+            }
+            let count = count
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| format!("expected a count, found {:?}", count))?;
+            bag.set(colour, count);
+        }
+        Ok(bag)
+    }
+
+    /// Product of the cube counts. Not 1 for an empty bag in case a game
+    /// takes nothing out.
+    pub fn power(&self) -> u32 {
+        match self.cubes.is_empty() {
+            true => 0,
+            false => self.cubes.values().product(),
+        }
+    }
+}
+
+impl Display for Bag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let cubes: Vec<String> = self
+            .cubes
+            .iter()
+            .map(|(colour, count)| format!("{}:{}", colour, count))
+            .collect();
+        write!(f, "{}", cubes.join(","))
+    }
+}
+
+/// One handful of cubes shown from the bag, by colour.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Draw {
+    cubes: BTreeMap<String, u32>,
+}
+
+impl Draw {
+    pub fn get(&self, colour: &str) -> u32 {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.cubes
+            .iter()
+            .map(|(colour, count)| (colour.as_str(), *count))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: i32,
+    pub draws: Vec<Draw>,
+}
+
+/// A draw that took out more cubes of a colour than the bag holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Which draw of the game, counting from 1.
+    pub draw: usize,
+    pub colour: String,
+    pub count: u32,
+    pub available: u32,
+}
+
+/// What one game says about a bag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameCheck {
+    pub id: i32,
+    pub violations: Vec<Violation>,
+    /// The fewest cubes of each colour the game could have been played with.
+    pub minimum_bag: Bag,
+}
+
+impl GameCheck {
+    pub fn is_possible(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+pub struct Games {
+    games: Vec<Game>,
+    bag: Bag,
+}
+
+impl Games {
+    pub fn games(&self) -> &[Game] {
+        &self.games
+    }
+
+    /// The bag given with the `bag` option, or the puzzle's bag.
+    pub fn bag(&self) -> &Bag {
+        &self.bag
+    }
+
+    /// Checks every game against `bag`, so other bags can be tried.
+    pub fn check(&self, bag: &Bag) -> Vec<GameCheck> {
+        self.games.iter().map(|x| check_against(x, bag)).collect()
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Games;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Options::new())
+    }
+
+    fn parse_with(input: &str, options: &Options) -> Result<Self::Input> {
+        let bag = match options.get("bag") {
+            Some(x) => Bag::parse(x).map_err(|e| parse::invalid_option(DAY, "bag", x, &e))?,
+            None => Bag::default(),
+        };
+        let games = parse::lines(DAY, input)
+            .map(|line| parse_game(&line))
+            .collect::<Result<Vec<Game>>>()?;
+        Ok(Games { games, bag })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+
+    fn report(input: &Self::Input, format: ReportFormat) -> Option<String> {
+        Some(report(input, format))
+    }
+}
+
+// Parses a "Game N: 3 blue, 4 red; 1 red" line. Whitespace around the
+// separators is not significant and colours may come in any order.
+fn parse_game(line: &Line) -> Result<Game> {
+    let game = line.strip_prefix(line.text.trim_start(), "Game")?;
+    let (id, steps) = line.split_once(game, ":")?;
+    let id = line.parse::<i32>(id.trim())?;
+
+    let mut draws: Vec<Draw> = vec![];
+    for step in steps.split(";") {
+        let mut draw = Draw::default();
+        for action in step.split(",") {
+            let action = action.trim();
+            let mut tokens = action.split_whitespace();
+            let (count, colour) = match (tokens.next(), tokens.next(), tokens.next()) {
+                (Some(count), Some(colour), None) => (count, colour),
+                _ => return Err(line.error(action, "expected a count and a colour")),
+            };
+            let count = line.parse::<u32>(count)?;
+            if !colour.chars().all(|x| x.is_ascii_alphabetic()) {
+                return Err(line.error(colour, "expected a colour"));
+            }
+            *draw.cubes.entry(colour.to_string()).or_insert(0) += count;
+        }
+        draws.push(draw);
+    }
+    Ok(Game { id, draws })
+}
+
+fn part1(games: &Games) -> i32 {
+    games
+        .check(games.bag())
+        .iter()
+        .filter(|x| x.is_possible())
+        .map(|x| x.id)
+        .sum()
+}
+
+fn part2(games: &Games) -> u32 {
+    games
+        .check(games.bag())
+        .iter()
+        .map(|x| x.minimum_bag.power())
+        .sum()
+}
+
+fn check_against(game: &Game, bag: &Bag) -> GameCheck {
+    let mut violations: Vec<Violation> = vec![];
+    let mut minimum_bag = Bag::new();
+    for (idx, draw) in game.draws.iter().enumerate() {
+        for (colour, count) in draw.iter() {
+            let available = bag.get(colour);
+            if count > available {
+                violations.push(Violation {
+                    draw: idx + 1,
+                    colour: colour.to_string(),
+                    count,
+                    available,
+                });
+            }
+            if count > minimum_bag.get(colour) {
+                minimum_bag.set(colour, count);
+            }
+        }
+    }
+    GameCheck {
+        id: game.id,
+        violations,
+        minimum_bag,
+    }
+}
+
+fn describe(violations: &[Violation]) -> String {
+    let described: Vec<String> = violations
+        .iter()
+        .map(|x| {
+            format!(
+                "draw {}: {} {} > {}",
+                x.draw, x.count, x.colour, x.available
+            )
+        })
+        .collect();
+    described.join("; ")
+}
+
+/// One row per game: whether the bag allows it, the draws that broke it,
+/// and the smallest bag that would.
+fn report(games: &Games, format: ReportFormat) -> String {
+    let checks = games.check(games.bag());
+    let mut out = String::new();
+    match format {
+        ReportFormat::Text => {
+            out.push_str(&format!("Bag: {}\n", games.bag()));
+            out.push_str(&format!(
+                "{:<6} {:<8} {:<28} {:>8}  {}\n",
+                "Game", "Possible", "Minimum bag", "Power", "Violations"
+            ));
+            for x in checks {
+                out.push_str(&format!(
+                    "{:<6} {:<8} {:<28} {:>8}  {}\n",
+                    x.id,
+                    if x.is_possible() { "yes" } else { "no" },
+                    x.minimum_bag.to_string(),
+                    x.minimum_bag.power(),
+                    describe(&x.violations)
+                ));
+            }
+        }
+        ReportFormat::Csv => {
+            out.push_str("game,possible,minimum_bag,power,violations\n");
+            for x in checks {
+                out.push_str(&format!(
+                    "{},{},{},{},{}\n",
+                    x.id,
+                    x.is_possible(),
+                    x.minimum_bag.to_string().replace(',', " "),
+                    x.minimum_bag.power(),
+                    describe(&x.violations)
+                ));
+            }
+        }
+        ReportFormat::Json => {
+            let rows: Vec<String> = checks
+                .iter()
+                .map(|x| {
+                    let minimum: Vec<String> = x
+                        .minimum_bag
+                        .cubes
+                        .iter()
+                        .map(|(colour, count)| format!("\"{}\": {}", colour, count))
+                        .collect();
+                    let violations: Vec<String> = x
+                        .violations
+                        .iter()
+                        .map(|v| {
+                            format!(
+                                "{{\"draw\": {}, \"colour\": \"{}\", \"count\": {}, \"available\": {}}}",
+                                v.draw, v.colour, v.count, v.available
+                            )
+                        })
+                        .collect();
+                    format!(
+                        "  {{\"game\": {}, \"possible\": {}, \"minimum_bag\": {{{}}}, \"power\": {}, \"violations\": [{}]}}",
+                        x.id,
+                        x.is_possible(),
+                        minimum.join(", "),
+                        x.minimum_bag.power(),
+                        violations.join(", ")
+                    )
+                })
+                .collect();
+            out.push_str(&format!("[\n{}\n]\n", rows.join(",\n")));
+        }
+    }
+    out
+}
+
+utils::example_tests!(crate::Day2, 2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/day2/test.txt");
+
+    fn games(input: &str, bag: &str) -> Games {
+        let mut options = Options::new();
+        options.set("bag", bag);
+        Day2::parse_with(input, &options).unwrap()
+    }
+
+    #[test]
+    fn parse_bag() {
+        let bag = Bag::parse(" red:1, purple : 20 ,").unwrap();
+        assert_eq!(bag.get("red"), 1);
+        assert_eq!(bag.get("purple"), 20);
+        assert_eq!(bag.get("blue"), 0);
+        assert_eq!(bag.to_string(), "purple:20,red:1");
+        assert_eq!(Bag::parse("").unwrap(), Bag::new());
+        assert!(Bag::parse("red:-1").is_err());
+        assert!(Bag::parse("red").is_err());
+        assert!(Bag::parse("red:x").is_err());
+        assert!(Bag::parse("r3d:1").is_err());
+    }
+
+    #[test]
+    fn negative_bag_option() {
+        let mut options = Options::new();
+        options.set("bag", "red:-1");
+        let e = Day2::parse_with(EXAMPLE, &options).err().unwrap();
+        assert_eq!(e.line, 0);
+        assert_eq!(e.text, "red:-1");
+    }
+
+    #[test]
+    fn what_if_bag() {
+        // With 20 of each colour every example game is possible
+        let games = games(EXAMPLE, "red:20,green:20,blue:20");
+        assert_eq!(Day2::part1(&games), Answer::from(15));
+        assert_eq!(Day2::part2(&games), Answer::from(2286_u32));
+        let checks = games.check(&Bag::default());
+        let possible: Vec<i32> = checks
+            .iter()
+            .filter(|x| x.is_possible())
+            .map(|x| x.id)
+            .collect();
+        assert_eq!(possible, [1, 2, 5]);
+    }
+
+    #[test]
+    fn violations_per_draw_and_colour() {
+        let games = games(EXAMPLE, "red:12,green:13,blue:14");
+        let check = &games.check(games.bag())[3];
+        assert_eq!(check.id, 4);
+        assert_eq!(
+            check.violations,
+            [
+                Violation {
+                    draw: 3,
+                    colour: "blue".to_string(),
+                    count: 15,
+                    available: 14,
+                },
+                Violation {
+                    draw: 3,
+                    colour: "red".to_string(),
+                    count: 14,
+                    available: 12,
+                },
+            ]
+        );
+        assert_eq!(check.minimum_bag.to_string(), "blue:15,green:3,red:14");
+        assert_eq!(check.minimum_bag.power(), 630);
+    }
+
+    #[test]
+    fn spacing_and_colour_order() {
+        let spaced = games("Game 7: 4 red, 3 blue; 2 green, 1 red", "");
+        let cramped = games("Game 7:3 blue ,4 red;  1 red,2 green", "");
+        assert_eq!(spaced.games(), cramped.games());
+        assert_eq!(cramped.games()[0].draws[0].get("blue"), 3);
+        assert_eq!(cramped.games()[0].draws[1].get("green"), 2);
+    }
+
+    #[test]
+    fn negative_draw_count() {
+        let e = Day2::parse("Game 1: -5 red").err().unwrap();
+        assert_eq!((e.line, e.column), (1, 9));
+        assert_eq!(e.text, "-5");
+    }
+
+    #[test]
+    fn other_colours() {
+        let input = "Game 1: 2 purple, 1 red; 5 purple\nGame 2: 1 orange";
+        let games = games(input, "purple:4,red:1");
+        let checks = games.check(games.bag());
+        assert_eq!(
+            checks[0].violations,
+            [Violation {
+                draw: 2,
+                colour: "purple".to_string(),
+                count: 5,
+                available: 4,
+            }]
+        );
+        assert_eq!(checks[0].minimum_bag.to_string(), "purple:5,red:1");
+        assert_eq!(checks[1].violations[0].available, 0);
+        assert_eq!(Day2::part2(&games), Answer::from(5 + 1_u32));
+    }
+}
@@ -0,0 +1,77 @@
+use utils::{Answer, Grid, GridNumber, Point, Solution};
+
+const DAY: u32 = 3;
+
+#[derive(Clone)]
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<GridNumber>,
+}
+
+impl Schematic {
+    fn build_grid(input: &str) -> utils::Result<Schematic> {
+        let grid = Grid::parse(DAY, input, Some)?;
+        let numbers = grid.numbers(DAY)?;
+        Ok(Schematic { grid, numbers })
+    }
+
+    fn is_symbol(&self, point: Point) -> bool {
+        match self.grid.get(point) {
+            Some(x) => !x.is_ascii_digit() && *x != '.',
+            None => false,
+        }
+    }
+
+    /// Numbers next to a symbol.
+    fn part_numbers(&self) -> impl Iterator<Item = &GridNumber> {
+        self.numbers
+            .iter()
+            .filter(|x| x.neighbours(&self.grid).any(|p| self.is_symbol(p)))
+    }
+
+    /// Products of the two numbers around each `*` that touches exactly two.
+    /// Ratios too large for a `u64` come out as `None`.
+    fn gear_ratios(&self) -> impl Iterator<Item = Option<u64>> + '_ {
+        self.grid
+            .iter()
+            .filter(|(_, ch)| **ch == '*')
+            .filter_map(|(point, _)| {
+                let adjacent: Vec<&GridNumber> = self
+                    .numbers
+                    .iter()
+                    .filter(|x| x.is_adjacent(point))
+                    .collect();
+                match adjacent[..] {
+                    [a, b] => Some(a.value.checked_mul(b.value)),
+                    _ => None,
+                }
+            })
+    }
+}
+
+fn checked_sum(mut values: impl Iterator<Item = Option<u64>>) -> Answer {
+    values.try_fold(0_u64, |acc, x| acc.checked_add(x?)).map_or(
+        Answer::Failed("answer does not fit in a u64".to_string()),
+        Answer::from,
+    )
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Schematic;
+
+    fn parse(input: &str) -> utils::Result<Self::Input> {
+        Schematic::build_grid(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        checked_sum(input.part_numbers().map(|x| Some(x.value)))
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        checked_sum(input.gear_ratios())
+    }
+}
+
+utils::example_tests!(crate::Day3, 3);
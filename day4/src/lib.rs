@@ -0,0 +1,345 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use utils::parse::{self, Line};
+use utils::{Answer, ReportFormat, Result, Solution};
+
+const DAY: u32 = 4;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Card {
+    id: u32,
+    count: u32,
+    points: u32,
+    winning: Vec<u32>,
+    matched: Vec<u32>,
+}
+
+impl Hash for Card {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+fn get_game_points(line: &Line) -> Result<Card> {
+    let mut count: u32 = 0;
+    let (head, numbers) = line.split_once(line.text, ":")?;
+    let id = line.parse::<u32>(line.strip_prefix(head, "Card")?.trim())?;
+    let (left, right) = line.split_once(numbers, "|")?;
+
+    let winning: Vec<u32> = line.numbers(left)?;
+    let hash_set: HashSet<u32> = winning.iter().copied().collect();
+    let mut matched: Vec<u32> = vec![];
+    for num in line.numbers::<u32>(right)? {
+        if hash_set.contains(&num) {
+            count += 1;
+            matched.push(num);
+        }
+    }
+
+    let mut points = 0;
+    if count > 0 {
+        points = 2_u32.pow(count - 1);
+    }
+    Ok(Card {
+        id,
+        count,
+        points,
+        winning,
+        matched,
+    })
+}
+
+fn part1(cards: &[Card]) -> u32 {
+    let mut score = 0;
+    for card in cards {
+        score += card.points;
+    }
+    score
+}
+
+/// The ids of the cards that each copy of `card` wins. Wins that would run
+/// past the cards in the input are dropped; see [`missing_wins`].
+fn wins(positions: &HashMap<u32, usize>, card: &Card) -> Vec<u32> {
+    (card.id + 1..=card.id + card.count)
+        .filter(|x| positions.contains_key(x))
+        .collect()
+}
+
+/// Position of each card in the input, keyed by id.
+fn positions(cards: &[Card]) -> HashMap<u32, usize> {
+    cards.iter().enumerate().map(|(i, x)| (x.id, i)).collect()
+}
+
+/// How many copies of each card, originals included, end up being held,
+/// as `(id, copies)` in input order. Cards only win copies of higher ids, so
+/// one pass in id order settles each card's count before it hands out its
+/// own wins.
+pub fn copies_per_card(cards: &[Card]) -> Vec<(u32, u32)> {
+    let positions = positions(cards);
+    let mut order: Vec<usize> = (0..cards.len()).collect();
+    order.sort_by_key(|x| cards[*x].id);
+    let mut copies: Vec<u32> = vec![1; cards.len()];
+
+    for i in order {
+        for id in wins(&positions, &cards[i]) {
+            copies[positions[&id]] += copies[i];
+        }
+    }
+    cards.iter().map(|x| x.id).zip(copies).collect()
+}
+
+/// Cards whose matches would win copies of cards not in the input, as the
+/// card's id and the ids it misses out on.
+pub fn missing_wins(cards: &[Card]) -> Vec<(u32, Vec<u32>)> {
+    let positions = positions(cards);
+    cards
+        .iter()
+        .filter_map(|card| {
+            let missing: Vec<u32> = (card.id + 1..=card.id + card.count)
+                .filter(|x| !positions.contains_key(x))
+                .collect();
+            (!missing.is_empty()).then_some((card.id, missing))
+        })
+        .collect()
+}
+
+fn part2(cards: &[Card]) -> u32 {
+    copies_per_card(cards).iter().map(|(_, x)| x).sum()
+}
+
+fn join(values: &[u32], sep: &str) -> String {
+    let values: Vec<String> = values.iter().map(|x| x.to_string()).collect();
+    values.join(sep)
+}
+
+/// One row per card: its numbers, matches, points, how many copies were held
+/// and which cards each copy wins.
+fn report(cards: &[Card], format: ReportFormat) -> String {
+    let mut out = String::new();
+    let positions = positions(cards);
+    let rows: Vec<(&Card, u32)> = cards
+        .iter()
+        .zip(copies_per_card(cards))
+        .map(|(card, (_, copies))| (card, copies))
+        .collect();
+    let wins = |card: &Card| -> Vec<u32> { wins(&positions, card) };
+
+    match format {
+        ReportFormat::Text => {
+            let won = |card: &Card| match wins(card)[..] {
+                [] => "-".to_string(),
+                ref x => join(x, " "),
+            };
+            let wins_width = rows
+                .iter()
+                .map(|(card, _)| won(card).len())
+                .max()
+                .unwrap_or(0)
+                .max("Wins".len());
+            let width = rows
+                .iter()
+                .map(|(card, _)| join(&card.winning, " ").len())
+                .max()
+                .unwrap_or(0)
+                .max("Winning".len());
+            out.push_str(&format!(
+                "{:<6} {:>7} {:>8} {:>8}  {:<wins_width$}  {:<width$}  {}\n",
+                "Card", "Matches", "Points", "Copies", "Wins", "Winning", "Matched"
+            ));
+            for (card, copies) in rows {
+                out.push_str(&format!(
+                    "{:<6} {:>7} {:>8} {:>8}  {:<wins_width$}  {:<width$}  {}\n",
+                    card.id,
+                    card.count,
+                    card.points,
+                    copies,
+                    won(card),
+                    join(&card.winning, " "),
+                    join(&card.matched, " ")
+                ));
+            }
+        }
+        ReportFormat::Csv => {
+            out.push_str("card,winning,matched,matches,points,copies,wins\n");
+            for (card, copies) in rows {
+                out.push_str(&format!(
+                    "{},{},{},{},{},{},{}\n",
+                    card.id,
+                    join(&card.winning, " "),
+                    join(&card.matched, " "),
+                    card.count,
+                    card.points,
+                    copies,
+                    join(&wins(card), " ")
+                ));
+            }
+        }
+        ReportFormat::Json => {
+            let rows: Vec<String> = rows
+                .into_iter()
+                .map(|(card, copies)| {
+                    format!(
+                        "  {{\"card\": {}, \"winning\": [{}], \"matched\": [{}], \"matches\": {}, \"points\": {}, \"copies\": {}, \"wins\": [{}]}}",
+                        card.id,
+                        join(&card.winning, ", "),
+                        join(&card.matched, ", "),
+                        card.count,
+                        card.points,
+                        copies,
+                        join(&wins(card), ", ")
+                    )
+                })
+                .collect();
+            out.push_str(&format!("[\n{}\n]\n", rows.join(",\n")));
+        }
+    }
+    out
+}
+
+fn parse_cards(data: &str) -> Result<Vec<Card>> {
+    let mut cards: Vec<Card> = vec![];
+    let mut seen: HashSet<u32> = HashSet::new();
+
+    for line in parse::lines(DAY, data) {
+        let card = get_game_points(&line)?;
+        if !seen.insert(card.id) {
+            let (head, _) = line.split_once(line.text, ":")?;
+            return Err(line.error(head, "duplicate card id"));
+        }
+        cards.push(card);
+    }
+    Ok(cards)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_cards(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+
+    fn warnings(input: &Self::Input) -> Vec<String> {
+        missing_wins(input)
+            .into_iter()
+            .map(|(id, missing)| {
+                format!(
+                    "card {} would win card(s) {}, which are not in the input",
+                    id,
+                    join(&missing, ", ")
+                )
+            })
+            .collect()
+    }
+
+    fn report(input: &Self::Input, format: ReportFormat) -> Option<String> {
+        Some(report(input, format))
+    }
+}
+
+utils::example_tests!(crate::Day4, 4);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/day4/test.txt");
+
+    #[test]
+    fn report_formats() {
+        let cards = parse_cards(EXAMPLE).unwrap();
+        let text = report(&cards, ReportFormat::Text);
+        let row: Vec<&str> = text.lines().nth(1).unwrap().split_whitespace().collect();
+        assert_eq!(
+            row,
+            [
+                "1", "4", "8", "1", "2", "3", "4", "5", "41", "48", "83", "86", "17", "83", "86",
+                "17", "48"
+            ]
+        );
+        let csv = report(&cards, ReportFormat::Csv);
+        assert_eq!(
+            csv.lines().nth(1),
+            Some("1,41 48 83 86 17,83 86 17 48,4,8,1,2 3 4 5")
+        );
+        let json = report(&cards, ReportFormat::Json);
+        assert!(json.contains(
+            "{\"card\": 1, \"winning\": [41, 48, 83, 86, 17], \"matched\": [83, 86, 17, 48], \"matches\": 4, \"points\": 8, \"copies\": 1, \"wins\": [2, 3, 4, 5]}"
+        ));
+        assert_eq!(json.lines().count(), cards.len() + 2);
+    }
+
+    #[test]
+    fn report_lists_wins_across_gaps() {
+        // Card 1 wins cards 2 and 4; there is no card 3 to win
+        let input = "Card 1: 1 2 3 | 1 2 3\nCard 2: 5 | 6\nCard 4: 7 | 8";
+        let cards = parse_cards(input).unwrap();
+        let text = report(&cards, ReportFormat::Text);
+        let row: Vec<&str> = text.lines().nth(1).unwrap().split_whitespace().collect();
+        assert_eq!(row[..7], ["1", "3", "4", "1", "2", "4", "1"]);
+    }
+
+    #[test]
+    fn wins_past_the_last_card() {
+        let cards = parse_cards("Card 1: 1 2 | 1 2\nCard 2: 3 4 | 3 5").unwrap();
+        assert_eq!(copies_per_card(&cards), vec![(1, 1), (2, 2)]);
+        assert_eq!(missing_wins(&cards), vec![(1, vec![3]), (2, vec![3])]);
+        assert_eq!(
+            Day4::warnings(&cards),
+            [
+                "card 1 would win card(s) 3, which are not in the input",
+                "card 2 would win card(s) 3, which are not in the input",
+            ]
+        );
+    }
+
+    #[test]
+    fn ids_out_of_order_with_gaps() {
+        // Card 3 wins card 4 and card 1 wins card 2; there is no card 5
+        let input = "Card 10: 9 | 8\nCard 3: 1 | 1\nCard 1: 2 | 2\nCard 4: 5 | 5\nCard 2: 7 | 6";
+        let cards = parse_cards(input).unwrap();
+        assert_eq!(
+            copies_per_card(&cards),
+            vec![(10, 1), (3, 1), (1, 1), (4, 2), (2, 2)]
+        );
+        assert_eq!(missing_wins(&cards), vec![(4, vec![5])]);
+        assert_eq!(part2(&cards), 7);
+    }
+
+    #[test]
+    fn duplicate_card_id() {
+        let e = parse_cards("Card 1: 1 | 1\nCard  1: 2 | 3").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert_eq!(e.message, "duplicate card id");
+        assert_eq!(e.text, "Card  1");
+    }
+
+    #[test]
+    fn copies_on_example() {
+        let cards = parse_cards(EXAMPLE).unwrap();
+        assert_eq!(
+            copies_per_card(&cards),
+            vec![(1, 1), (2, 2), (3, 4), (4, 8), (5, 14), (6, 1)]
+        );
+    }
+
+    #[test]
+    fn copies_in_reverse_order() {
+        let reversed: Vec<&str> = EXAMPLE.lines().rev().collect();
+        let cards = parse_cards(&reversed.join("\n")).unwrap();
+        assert_eq!(
+            copies_per_card(&cards),
+            vec![(6, 1), (5, 14), (4, 8), (3, 4), (2, 2), (1, 1)]
+        );
+        assert_eq!(part2(&cards), 30);
+    }
+}
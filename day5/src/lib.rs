@@ -0,0 +1,694 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use utils::{
+    parse, Answer, Interval, IntervalSet, Options, PiecewiseMap, ReportFormat, Result, Solution,
+};
+
+const DAY: u32 = 5;
+
+#[derive(Clone, Debug)]
+pub struct Mapper {
+    to: String,
+    map: PiecewiseMap,
+    // Header line, then each range's line and source interval as written
+    line: usize,
+    ranges: Vec<(usize, Interval<i64>)>,
+}
+
+/// Something suspect about an almanac's structure, located by line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Warning {
+    /// Two ranges in one map share source values; the earlier one wins.
+    OverlappingRanges {
+        category: String,
+        first_line: usize,
+        second_line: usize,
+        overlap: Interval<i64>,
+    },
+    /// A second map from the same category replaces the first.
+    DuplicateMap {
+        category: String,
+        first_line: usize,
+        second_line: usize,
+    },
+    /// A map converts into a category that has no map of its own.
+    DanglingCategory {
+        category: String,
+        to: String,
+        line: usize,
+    },
+    /// A map that the chain starting from seed never uses.
+    UnreachableMap { category: String, line: usize },
+    /// Categories that convert round into each other forever.
+    Cycle { categories: Vec<String> },
+    /// Following the chain from seed never gets to location.
+    LocationUnreachable,
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::OverlappingRanges {
+                category,
+                first_line,
+                second_line,
+                overlap,
+            } => write!(
+                f,
+                "line {}: {} range overlaps line {} on {}",
+                second_line, category, first_line, overlap
+            ),
+            Warning::DuplicateMap {
+                category,
+                first_line,
+                second_line,
+            } => write!(
+                f,
+                "line {}: second {} map replaces the one on line {}",
+                second_line, category, first_line
+            ),
+            Warning::DanglingCategory { category, to, line } => write!(
+                f,
+                "line {}: {} maps to {}, which has no map",
+                line, category, to
+            ),
+            Warning::UnreachableMap { category, line } => {
+                write!(
+                    f,
+                    "line {}: {} map is never reached from seed",
+                    line, category
+                )
+            }
+            Warning::Cycle { categories } => {
+                write!(f, "cycle: {} -> {}", categories.join(" -> "), categories[0])
+            }
+            Warning::LocationUnreachable => write!(f, "seed never reaches location"),
+        }
+    }
+}
+
+/// How part 2 finds the lowest location.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// Map the seed ranges forwards and take the smallest location.
+    Map,
+    /// Search locations upwards from 0 for one with a seed; see
+    /// [`Almanac::lowest_reachable_location`].
+    Search,
+}
+
+impl Strategy {
+    /// Reads the `strategy` option: `map` or `search`.
+    pub fn from_option(value: &str) -> std::result::Result<Strategy, String> {
+        match value {
+            "map" => Ok(Strategy::Map),
+            "search" => Ok(Strategy::Search),
+            _ => Err("expected map or search".to_string()),
+        }
+    }
+}
+
+/// The seeds and every conversion stage, keyed by source category, with the
+/// whole seed to location chain composed once up front.
+#[derive(Clone, Debug)]
+pub struct Almanac {
+    seeds: Vec<i64>,
+    mappers: HashMap<String, Mapper>,
+    seed_to_location: Option<PiecewiseMap>,
+    duplicates: Vec<Warning>,
+    strategy: Strategy,
+}
+
+impl Almanac {
+    pub fn seeds(&self) -> &[i64] {
+        &self.seeds
+    }
+
+    /// Composes the stages from category `from` to category `to` into a
+    /// single map, or `None` if following the chain from `from` never
+    /// reaches `to`.
+    pub fn chain(&self, from: &str, to: &str) -> Option<PiecewiseMap> {
+        let stages = self.stages(from, to)?;
+        Some(
+            stages
+                .iter()
+                .fold(PiecewiseMap::new(), |acc, x| acc.then(&x.map)),
+        )
+    }
+
+    /// What `value` in category `from` corresponds to in category `to`.
+    pub fn convert(&self, value: i64, from: &str, to: &str) -> Option<i64> {
+        self.chain(from, to).map(|x| x.apply(value))
+    }
+
+    /// The stages from category `from` to category `to`, in order.
+    fn stages(&self, from: &str, to: &str) -> Option<Vec<&Mapper>> {
+        let mut stages: Vec<&Mapper> = vec![];
+        let mut current = from;
+        // A chain longer than the number of stages must be going round a loop
+        for _ in 0..=self.mappers.len() {
+            if current == to {
+                return Some(stages);
+            }
+            let mapper = self.mappers.get(current)?;
+            stages.push(mapper);
+            current = &mapper.to;
+        }
+        None
+    }
+
+    /// Runs the chain backwards, inverting one stage at a time: every value
+    /// in category `from` that ends up in `values` in category `to`.
+    pub fn preimage(
+        &self,
+        values: &IntervalSet<i64>,
+        from: &str,
+        to: &str,
+    ) -> Option<IntervalSet<i64>> {
+        let stages = self.stages(from, to)?;
+        Some(
+            stages
+                .iter()
+                .rev()
+                .fold(values.clone(), |acc, x| x.map.preimage(&acc)),
+        )
+    }
+
+    /// The seeds that end up at `location`, or `None` if the chain from seed
+    /// never reaches location or `location` is `i64::MAX`, which no
+    /// half-open interval can hold.
+    pub fn seeds_for_location(&self, location: i64) -> Option<IntervalSet<i64>> {
+        let location = [Interval::new(location, location.checked_add(1)?)]
+            .into_iter()
+            .collect();
+        self.preimage(&location, "seed", "location")
+    }
+
+    /// The seeds as `start length` pairs, read as intervals.
+    pub fn seed_ranges(&self) -> IntervalSet<i64> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|x| Interval::new(x[0], x[0] + x[1]))
+            .collect()
+    }
+
+    /// The smallest location reachable from `seeds`, searching upwards from
+    /// location 0 one stretch at a time, where a stretch is bounded by the
+    /// images of the composed map's breakpoints, until one has a source seed.
+    pub fn lowest_reachable_location(&self, seeds: &IntervalSet<i64>) -> Option<i64> {
+        let map = self.seed_to_location.as_ref()?;
+        let mut bounds: Vec<i64> = vec![0];
+        for (x, offset) in map.pieces() {
+            bounds.extend([x.start, x.end, x.start + offset, x.end + offset]);
+        }
+        bounds.retain(|x| *x >= 0);
+        bounds.sort();
+        bounds.dedup();
+        let last = *bounds.last().unwrap();
+        // Past every boundary the map is the identity, so one last open stretch
+        bounds.push(last.max(seeds.iter().map(|x| x.end).max()?));
+
+        bounds.windows(2).find_map(|x| {
+            let stretch = [Interval::new(x[0], x[1])].into_iter().collect();
+            let sources = map.preimage(&stretch).intersection(seeds);
+            map.map_set(&sources).min()
+        })
+    }
+
+    /// The composed seed to location map, if the chain gets there.
+    pub fn seed_to_location(&self) -> Option<&PiecewiseMap> {
+        self.seed_to_location.as_ref()
+    }
+
+    /// Checks the almanac's structure: overlapping ranges within a map,
+    /// replaced maps, categories with no map, maps never used, cycles, and
+    /// whether seed reaches location at all.
+    pub fn validate(&self) -> Vec<Warning> {
+        let mut warnings = self.duplicates.clone();
+
+        let mut categories: Vec<(&String, &Mapper)> = self.mappers.iter().collect();
+        categories.sort_by_key(|(_, x)| x.line);
+
+        for (category, mapper) in &categories {
+            for (i, (first_line, first)) in mapper.ranges.iter().enumerate() {
+                for (second_line, second) in &mapper.ranges[i + 1..] {
+                    if let Some(overlap) = first.intersect(second) {
+                        warnings.push(Warning::OverlappingRanges {
+                            category: category.to_string(),
+                            first_line: *first_line,
+                            second_line: *second_line,
+                            overlap,
+                        });
+                    }
+                }
+            }
+        }
+
+        for (category, mapper) in &categories {
+            if mapper.to != "location" && !self.mappers.contains_key(&mapper.to) {
+                warnings.push(Warning::DanglingCategory {
+                    category: category.to_string(),
+                    to: mapper.to.clone(),
+                    line: mapper.line,
+                });
+            }
+        }
+
+        // Each category has one successor, so walking from every start finds
+        // each cycle once, the first time the walk runs into its own path
+        let mut done: HashSet<&str> = HashSet::new();
+        for (category, _) in &categories {
+            let mut path: Vec<&str> = vec![];
+            let mut current = category.as_str();
+            while !done.contains(current) {
+                if let Some(idx) = path.iter().position(|x| *x == current) {
+                    let categories = path[idx..].iter().map(|x| x.to_string()).collect();
+                    warnings.push(Warning::Cycle { categories });
+                    break;
+                }
+                path.push(current);
+                match self.mappers.get(current) {
+                    Some(x) => current = &x.to,
+                    None => break,
+                }
+            }
+            done.extend(path);
+        }
+
+        let mut reached: HashSet<&str> = HashSet::new();
+        let mut current = "seed";
+        while let Some(mapper) = self.mappers.get(current) {
+            if !reached.insert(current) {
+                break;
+            }
+            current = &mapper.to;
+        }
+        for (category, mapper) in &categories {
+            if !reached.contains(category.as_str()) {
+                warnings.push(Warning::UnreachableMap {
+                    category: category.to_string(),
+                    line: mapper.line,
+                });
+            }
+        }
+
+        if self.seed_to_location.is_none() {
+            warnings.push(Warning::LocationUnreachable);
+        }
+        warnings
+    }
+}
+
+fn parse_input(input: &str, strategy: Strategy) -> Result<Almanac> {
+    let mut mappers_map: HashMap<String, Mapper> = HashMap::new();
+    let mut duplicates: Vec<Warning> = vec![];
+    let mut insert = |from: String, mapper: Mapper| {
+        if let Some(old) = mappers_map.get(&from) {
+            duplicates.push(Warning::DuplicateMap {
+                category: from.clone(),
+                first_line: old.line,
+                second_line: mapper.line,
+            });
+        }
+        mappers_map.insert(from, mapper);
+    };
+    let mut lines = parse::lines(DAY, input);
+
+    // Get seeds from head
+    let head = lines
+        .next()
+        .ok_or_else(|| parse::unexpected_end(DAY, input, "seeds"))?;
+    let tokens: Vec<&str> = head
+        .strip_prefix(head.text, "seeds: ")?
+        .split_whitespace()
+        .collect();
+    let mut seeds: Vec<i64> = vec![];
+    for token in &tokens {
+        let seed = head.parse::<i64>(token)?;
+        if seed < 0 {
+            return Err(head.error(token, "expected a non-negative number"));
+        }
+        seeds.push(seed);
+    }
+    // Part 2 reads the seeds as "start length" pairs, whose ends must fit
+    for (i, x) in seeds.chunks_exact(2).enumerate() {
+        if x[0].checked_add(x[1]).is_none() {
+            return Err(head.error(tokens[2 * i + 1], "seed range is too long"));
+        }
+    }
+
+    // Get mappers from the following blocks, each a "from-to-to map:" header then ranges
+    let mut current: Option<(String, Mapper)> = None;
+    for line in lines {
+        if line.text.is_empty() {
+            continue;
+        }
+
+        if let Some(chead) = line.text.strip_suffix(" map:") {
+            let (from, to) = line.split_once(chead, "-to-")?;
+            if let Some((from, mapper)) = current.take() {
+                insert(from, mapper);
+            }
+            current = Some((
+                from.to_string(),
+                Mapper {
+                    to: to.to_string(),
+                    map: PiecewiseMap::new(),
+                    line: line.number,
+                    ranges: vec![],
+                },
+            ));
+            continue;
+        }
+
+        let (_, mapper) = current
+            .as_mut()
+            .ok_or_else(|| line.error(line.text, "expected a map header"))?;
+        let vals: Vec<i64> = line.numbers(line.text)?;
+        if vals.len() != 3 {
+            return Err(line.error(line.text, "expected three numbers"));
+        }
+
+        if vals.iter().any(|x| *x < 0) {
+            return Err(line.error(line.text, "expected non-negative numbers"));
+        }
+
+        // Each line is "dest source length"; where ranges overlap the first wins
+        let (dest, source, length) = (vals[0], vals[1], vals[2]);
+        let end = match (source.checked_add(length), dest.checked_add(length)) {
+            (Some(end), Some(_)) => end,
+            _ => return Err(line.error(line.text, "range is too long")),
+        };
+        let source = Interval::new(source, end);
+        mapper.map.insert(source, dest - source.start);
+        mapper.ranges.push((line.number, source));
+    }
+    if let Some((from, mapper)) = current.take() {
+        insert(from, mapper);
+    }
+
+    let mut almanac = Almanac {
+        seeds,
+        mappers: mappers_map,
+        seed_to_location: None,
+        duplicates,
+        strategy,
+    };
+    almanac.seed_to_location = almanac.chain("seed", "location");
+    Ok(almanac)
+}
+
+fn part1(almanac: &Almanac) -> Option<i64> {
+    let map = almanac.seed_to_location()?;
+    almanac.seeds.iter().map(|x| map.apply(*x)).min()
+}
+
+fn part2(almanac: &Almanac) -> Option<i64> {
+    let seeds = almanac.seed_ranges();
+    match almanac.strategy {
+        Strategy::Map => almanac.seed_to_location()?.map_set(&seeds).min(),
+        Strategy::Search => almanac.lowest_reachable_location(&seeds),
+    }
+}
+
+/// The composed seed to location map, one piece per row.
+fn report(almanac: &Almanac, format: ReportFormat) -> String {
+    let Some(map) = almanac.seed_to_location() else {
+        return match format {
+            ReportFormat::Json => "[]\n".to_string(),
+            ReportFormat::Csv => "start,end,offset\n".to_string(),
+            ReportFormat::Text => format!("{}\n", Warning::LocationUnreachable),
+        };
+    };
+    let mut out = String::new();
+    match format {
+        ReportFormat::Text => {
+            out.push_str("Seed to location:\n");
+            out.push_str(&map.to_string());
+        }
+        ReportFormat::Csv => {
+            out.push_str("start,end,offset\n");
+            for (x, offset) in map.pieces() {
+                out.push_str(&format!("{},{},{}\n", x.start, x.end, offset));
+            }
+        }
+        ReportFormat::Json => {
+            let rows: Vec<String> = map
+                .pieces()
+                .map(|(x, offset)| {
+                    format!(
+                        "  {{\"start\": {}, \"end\": {}, \"offset\": {}}}",
+                        x.start, x.end, offset
+                    )
+                })
+                .collect();
+            out.push_str(&format!("[\n{}\n]\n", rows.join(",\n")));
+        }
+    }
+    out
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Options::new())
+    }
+
+    fn parse_with(input: &str, options: &Options) -> Result<Self::Input> {
+        let strategy = match options.get("strategy") {
+            Some(x) => Strategy::from_option(x)
+                .map_err(|e| parse::invalid_option(DAY, "strategy", x, &e))?,
+            None => Strategy::Map,
+        };
+        parse_input(input, strategy)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn warnings(input: &Self::Input) -> Vec<String> {
+        input.validate().iter().map(|x| x.to_string()).collect()
+    }
+
+    fn report(input: &Self::Input, format: ReportFormat) -> Option<String> {
+        Some(report(input, format))
+    }
+}
+
+utils::example_tests!(crate::Day5, 5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn both_strategies(input: &str) {
+        let mut options = Options::new();
+        let by_map = Day5::parse_with(input, &options).unwrap();
+        options.set("strategy", "search");
+        let by_search = Day5::parse_with(input, &options).unwrap();
+        assert_eq!(by_search.strategy, Strategy::Search);
+        assert_eq!(Day5::part2(&by_map), Day5::part2(&by_search));
+        assert_ne!(Day5::part2(&by_map), Answer::Unsolved);
+    }
+
+    const EXAMPLE: &str = include_str!("../../inputs/day5/test.txt");
+
+    #[test]
+    fn chain_matches_stages() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        let chain = almanac.chain("soil", "humidity").unwrap();
+        let stages = almanac.stages("soil", "humidity").unwrap();
+        let names: Vec<&str> = stages.iter().map(|x| x.to.as_str()).collect();
+        assert_eq!(
+            names,
+            ["fertilizer", "water", "light", "temperature", "humidity"]
+        );
+        for x in 0..120 {
+            let staged = stages.iter().fold(x, |acc, stage| stage.map.apply(acc));
+            assert_eq!(chain.apply(x), staged, "at {}", x);
+            assert_eq!(almanac.convert(x, "soil", "humidity"), Some(staged));
+        }
+        // The puzzle's walkthrough: seed 79 is soil 81 and humidity 78
+        assert_eq!(almanac.convert(79, "seed", "soil"), Some(81));
+        assert_eq!(almanac.convert(81, "soil", "humidity"), Some(78));
+        assert_eq!(almanac.chain("humidity", "soil"), None);
+        assert_eq!(almanac.chain("soil", "soil"), Some(PiecewiseMap::new()));
+    }
+
+    #[test]
+    fn seeds_for_location() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        // Part 2's answer: seed 82 ends up at location 46
+        let seeds = almanac.seeds_for_location(46).unwrap();
+        assert!(seeds.contains(82));
+        for x in seeds.iter().flat_map(|x| x.start..x.end) {
+            assert_eq!(almanac.convert(x, "seed", "location"), Some(46));
+        }
+        for x in (0..200).filter(|x| !seeds.contains(*x)) {
+            assert_ne!(almanac.convert(x, "seed", "location"), Some(46));
+        }
+        assert_eq!(almanac.seeds_for_location(i64::MAX), None);
+    }
+
+    #[test]
+    fn preimage_of_a_range() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        let humidity: IntervalSet<i64> = [Interval::new(40, 60)].into_iter().collect();
+        let soil = almanac.preimage(&humidity, "soil", "humidity").unwrap();
+        for x in 0..200 {
+            let reached = almanac.convert(x, "soil", "humidity").unwrap();
+            assert_eq!(soil.contains(x), humidity.contains(reached), "at {}", x);
+        }
+        assert_eq!(almanac.preimage(&humidity, "humidity", "soil"), None);
+    }
+
+    fn warnings(input: &str) -> Vec<Warning> {
+        Day5::parse(input).unwrap().validate()
+    }
+
+    #[test]
+    fn example_is_valid() {
+        assert_eq!(warnings(EXAMPLE), vec![]);
+    }
+
+    #[test]
+    fn overlapping_ranges() {
+        let input = "seeds: 1\n\nseed-to-location map:\n10 0 5\n20 3 5\n30 8 2";
+        assert_eq!(
+            warnings(input),
+            vec![Warning::OverlappingRanges {
+                category: "seed".to_string(),
+                first_line: 4,
+                second_line: 5,
+                overlap: Interval::new(3, 5),
+            }]
+        );
+    }
+
+    #[test]
+    fn duplicate_map() {
+        let input = "seeds: 1\n\nseed-to-location map:\n0 0 1\n\nseed-to-location map:\n5 5 1";
+        assert_eq!(
+            warnings(input),
+            vec![Warning::DuplicateMap {
+                category: "seed".to_string(),
+                first_line: 3,
+                second_line: 6,
+            }]
+        );
+    }
+
+    #[test]
+    fn dangling_category() {
+        let input = "seeds: 1\n\nseed-to-soil map:\n0 0 1";
+        assert_eq!(
+            warnings(input),
+            vec![
+                Warning::DanglingCategory {
+                    category: "seed".to_string(),
+                    to: "soil".to_string(),
+                    line: 3,
+                },
+                Warning::LocationUnreachable,
+            ]
+        );
+    }
+
+    #[test]
+    fn cycle_from_seed() {
+        let input = "seeds: 1\n\nseed-to-soil map:\n0 0 1\n\nsoil-to-seed map:\n0 0 1";
+        assert_eq!(
+            warnings(input),
+            vec![
+                Warning::Cycle {
+                    categories: vec!["seed".to_string(), "soil".to_string()],
+                },
+                Warning::LocationUnreachable,
+            ]
+        );
+    }
+
+    #[test]
+    fn unreachable_cycle() {
+        // The walk from water joins the cycle part way round, which is only
+        // reported once, starting from where the first walk entered it
+        let input = "seeds: 1\n\nseed-to-location map:\n0 0 1\n\n\
+                     light-to-fertilizer map:\n0 0 1\n\n\
+                     water-to-light map:\n0 0 1\n\n\
+                     fertilizer-to-light map:\n0 0 1";
+        assert_eq!(
+            warnings(input),
+            vec![
+                Warning::Cycle {
+                    categories: vec!["light".to_string(), "fertilizer".to_string()],
+                },
+                Warning::UnreachableMap {
+                    category: "light".to_string(),
+                    line: 6,
+                },
+                Warning::UnreachableMap {
+                    category: "water".to_string(),
+                    line: 9,
+                },
+                Warning::UnreachableMap {
+                    category: "fertilizer".to_string(),
+                    line: 12,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn unreachable_map() {
+        let input = "seeds: 1\n\nseed-to-location map:\n0 0 1\n\nhumidity-to-location map:\n0 0 1";
+        assert_eq!(
+            warnings(input),
+            vec![Warning::UnreachableMap {
+                category: "humidity".to_string(),
+                line: 6,
+            }]
+        );
+    }
+
+    #[test]
+    fn location_unreachable() {
+        let input = "seeds: 1\n";
+        assert_eq!(warnings(input), vec![Warning::LocationUnreachable]);
+        assert_eq!(Day5::part1(&Day5::parse(input).unwrap()), Answer::Unsolved);
+    }
+
+    #[test]
+    fn report_lists_seed_to_location() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        let map = almanac.seed_to_location().unwrap();
+        let text = report(&almanac, ReportFormat::Text);
+        assert_eq!(text, format!("Seed to location:\n{}", map));
+        assert_eq!(text.lines().count(), map.pieces().count() + 1);
+        let csv = report(&almanac, ReportFormat::Csv);
+        let (x, offset) = map.pieces().next().unwrap();
+        assert_eq!(
+            csv.lines().nth(1),
+            Some(format!("{},{},{}", x.start, x.end, offset).as_str())
+        );
+        let json = report(&almanac, ReportFormat::Json);
+        assert_eq!(json.matches("\"offset\"").count(), map.pieces().count());
+    }
+
+    #[test]
+    fn search_agrees_on_example() {
+        both_strategies(EXAMPLE);
+    }
+
+    #[test]
+    fn search_agrees_on_input() {
+        both_strategies(include_str!("../../inputs/day5/input.txt"));
+    }
+}
@@ -0,0 +1,98 @@
+use utils::parse::{self, Line};
+use utils::{Answer, Result, Solution};
+
+const DAY: u32 = 6;
+
+pub struct Races {
+    times: Vec<i64>,
+    distances: Vec<i64>,
+    time: i64,
+    distance: i64,
+}
+
+fn get_nums_from_line(line: &Line) -> Result<Vec<i64>> {
+    let (_, values) = line.split_once(line.text, ":")?;
+    line.numbers(values)
+}
+
+fn get_value(line: &Line) -> Result<i64> {
+    let (_, values) = line.split_once(line.text, ":")?;
+    values
+        .replace(" ", "")
+        .parse::<i64>()
+        .map_err(|_| line.error(values, "expected a number"))
+}
+
+fn get_points(time: i64, distance: i64) -> i64 {
+    // Solving quadratic equation and distance between intercepts
+    let quad_sqrt_term = ((time.pow(2) - 4 * distance) as f64).sqrt();
+
+    let mut lb_float: f64 = ((time as f64) - quad_sqrt_term) / 2.;
+    let mut ub_float: f64 = ((time as f64) + quad_sqrt_term) / 2.;
+
+    // Have to account for float ends
+    if lb_float.fract() == 0.0 {
+        lb_float += 1.;
+    };
+
+    if ub_float.fract() == 0.0 {
+        ub_float -= 1.;
+    };
+
+    let lb = lb_float.ceil() as i64;
+    let ub = ub_float.floor() as i64;
+
+    let range: i64 = ub - lb + 1;
+    range
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = parse::lines(DAY, input);
+        let time_line = lines
+            .next()
+            .ok_or_else(|| parse::unexpected_end(DAY, input, "times"))?;
+        let distance_line = lines
+            .next()
+            .ok_or_else(|| parse::unexpected_end(DAY, input, "distances"))?;
+
+        let times = get_nums_from_line(&time_line)?;
+        let distances = get_nums_from_line(&distance_line)?;
+        if times.len() != distances.len() {
+            return Err(distance_line.error(
+                distance_line.text,
+                format!("expected {} distances", times.len()),
+            ));
+        }
+
+        Ok(Races {
+            times,
+            distances,
+            time: get_value(&time_line)?,
+            distance: get_value(&distance_line)?,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let length = input.times.len();
+
+        let mut total_points_p1: i64 = 1;
+        for idx in 0..length {
+            let time = input.times[idx];
+            let distance = input.distances[idx];
+            let points = get_points(time, distance);
+            total_points_p1 *= points
+        }
+        total_points_p1.into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        get_points(input.time, input.distance).into()
+    }
+}
+
+utils::example_tests!(crate::Day6, 6);
@@ -0,0 +1,91 @@
+use utils::parse::{self, Line};
+use utils::{Answer, Result, Solution};
+
+const DAY: u32 = 7;
+
+mod part1;
+mod part2;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+enum Card {
+    Ace,
+    King,
+    Queen,
+    Jack,
+    Ten,
+    Number(u64),
+}
+
+#[derive(Debug, Clone)]
+pub struct HandBid {
+    cards: Vec<Card>,
+    bid: u64,
+}
+
+fn parse_card(line: &Line, cards_text: &str, idx: usize, ch: char) -> Result<Card> {
+    let card = match ch {
+        'A' => Card::Ace,
+        'K' => Card::King,
+        'Q' => Card::Queen,
+        'J' => Card::Jack,
+        'T' => Card::Ten,
+        '2'..='9' => Card::Number(ch.to_digit(10).unwrap().into()),
+        _ => {
+            let text = &cards_text[idx..idx + ch.len_utf8()];
+            return Err(line.error(text, "expected a card"));
+        }
+    };
+    Ok(card)
+}
+
+fn parse_data(input: &str) -> Result<Vec<HandBid>> {
+    let mut hands: Vec<HandBid> = vec![];
+    for line in parse::lines(DAY, input) {
+        let (cards_text, bid) = line.split_once(line.text, " ")?;
+        let bid: u64 = line.parse(bid)?;
+        if cards_text.chars().count() != 5 {
+            return Err(line.error(cards_text, "expected five cards"));
+        }
+
+        let mut cards: Vec<Card> = vec![];
+        for (idx, ch) in cards_text.char_indices() {
+            cards.push(parse_card(&line, cards_text, idx, ch)?);
+        }
+        hands.push(HandBid { cards, bid });
+    }
+    Ok(hands)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<HandBid>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_data(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::part2(input).into()
+    }
+}
+
+utils::example_tests!(crate::Day7, 7);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_hands_keep_input_order() {
+        // Five jacks rank below five aces in both parts, and each pair of
+        // equal hands ranks in input order: 3 * 1 + 4 * 2 + 1 * 3 + 2 * 4
+        let hands = Day7::parse("AAAAA 1\nAAAAA 2\nJJJJJ 3\nJJJJJ 4").unwrap();
+        assert_eq!(Day7::part1(&hands), Answer::from(22_u64));
+        assert_eq!(Day7::part2(&hands), Answer::from(22_u64));
+    }
+}
@@ -1,15 +1,7 @@
 use std::collections::HashMap;
 use std::fmt::Display;
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
-enum Card {
-    Ace,
-    King,
-    Queen,
-    Jack,
-    Ten,
-    Number(u64),
-}
+use crate::{Card, HandBid};
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 enum Outcome {
@@ -44,11 +36,9 @@ impl Ord for Hand {
         let self_value = get_outcome_value(&self_outcome);
         let other_value = get_outcome_value(&other_outcome);
         if self_value == other_value {
-            for i in 0..=5 {
-                let self_card = &self.cards[i];
-                let other_card = &other.cards[i];
-                let self_card_value = get_token_value(&self_card);
-                let other_card_value = get_token_value(&other_card);
+            for (self_card, other_card) in self.cards.iter().zip(&other.cards) {
+                let self_card_value = get_token_value(self_card);
+                let other_card_value = get_token_value(other_card);
                 if self_card_value == other_card_value {
                     continue;
                 } else {
@@ -100,34 +90,15 @@ fn get_token_value(token: &Card) -> u64 {
     }
 }
 
-fn parse_data(input: String) -> Vec<Hand> {
-    let mut hands: Vec<Hand> = vec![];
-    for line in input.lines() {
-        let mut split_line = line.split(" ");
-        let cards_text = split_line.next().unwrap();
-        let bid: u64 = split_line.next().unwrap().parse::<u64>().unwrap();
-        let mut cards: Vec<Card> = vec![];
-
-        for ch in cards_text.chars() {
-            let card = match ch {
-                'A' => Card::Ace,
-                'K' => Card::King,
-                'Q' => Card::Queen,
-                'J' => Card::Jack,
-                'T' => Card::Ten,
-                _ => Card::Number(ch.to_digit(10).unwrap().into()),
-            };
-            cards.push(card);
-        }
-        let hand = Hand {
-            bid,
-            cards,
+fn build_hands(bids: &[HandBid]) -> Vec<Hand> {
+    bids.iter()
+        .map(|x| Hand {
+            bid: x.bid,
+            cards: x.cards.clone(),
             rank: 0,
             outcome: None,
-        };
-        hands.push(hand);
-    }
-    hands
+        })
+        .collect()
 }
 
 fn run_analysis(hands: Vec<Hand>) -> Vec<Hand> {
@@ -146,8 +117,8 @@ fn run_analysis(hands: Vec<Hand>) -> Vec<Hand> {
             if let Card::Jack = card {
                 continue;
             }
-            if get_token_value(&card) > high_card_value {
-                high_card_value = get_token_value(&card);
+            if get_token_value(card) > high_card_value {
+                high_card_value = get_token_value(card);
             }
             if *count == 5 {
                 highest_outcome = Outcome::FiveOfAKind;
@@ -184,7 +155,7 @@ fn run_analysis(hands: Vec<Hand>) -> Vec<Hand> {
                         println!("Hand: {:?}", hand);
                         println!("Jack count: {}", jack_count);
                         panic!("Invalid jack count");
-                    },
+                    }
                 },
                 Outcome::HighCard => match jack_count {
                     1 => Outcome::OnePair,
@@ -227,14 +198,13 @@ fn run_analysis(hands: Vec<Hand>) -> Vec<Hand> {
     ranked_hands
 }
 
-pub fn part2(data: &String) {
-    let data = data.clone();
-    let parsed_data = parse_data(data);
+pub fn part2(bids: &[HandBid]) -> u64 {
+    let parsed_data = build_hands(bids);
     let ranked_cards = run_analysis(parsed_data);
 
     let mut total: u64 = 0;
     for (i, hand) in ranked_cards.iter().enumerate() {
         total += (i + 1) as u64 * hand.bid;
     }
-    println!("Total: {}", total);
+    total
 }
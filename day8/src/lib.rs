@@ -0,0 +1,267 @@
+use std::collections::{HashMap, HashSet};
+use utils::parse::{self, Line};
+use utils::{math, Answer, Result, Solution};
+
+const DAY: u32 = 8;
+
+struct Node {
+    left: String,
+    right: String,
+}
+
+enum Direction {
+    Left,
+    Right,
+}
+
+pub struct Network {
+    nodes: HashMap<String, Node>,
+    directions: Vec<Direction>,
+    start_nodes: Vec<String>,
+}
+
+type ParsedNetwork = (HashMap<String, Node>, Vec<Direction>, Vec<String>);
+
+fn generate_nodes_hash_map(data: &str) -> Result<ParsedNetwork> {
+    let mut start_a_nodes: Vec<String> = vec![];
+
+    let mut directions: Vec<Direction> = vec![];
+    let mut nodes: HashMap<String, Node> = HashMap::new();
+    let mut lines = parse::lines(DAY, data);
+    let head = lines
+        .next()
+        .ok_or_else(|| parse::unexpected_end(DAY, data, "directions"))?;
+    for (idx, ch) in head.text.char_indices() {
+        match ch {
+            'L' => directions.push(Direction::Left),
+            'R' => directions.push(Direction::Right),
+            _ => {
+                let text = &head.text[idx..idx + ch.len_utf8()];
+                return Err(head.error(text, "expected a direction"));
+            }
+        }
+    }
+    if directions.is_empty() {
+        return Err(head.error(head.text, "expected a direction"));
+    }
+
+    // Remember where each node is referenced so dangling names can be reported
+    let mut references: Vec<(Line, &str)> = vec![];
+    for line in lines.skip_while(|x| x.text.is_empty()) {
+        let (value, rhs) = line.split_once(line.text, " = ")?;
+        let rhs = line.strip_prefix(rhs, "(")?;
+        let rhs = rhs
+            .strip_suffix(")")
+            .ok_or_else(|| line.error(rhs, "expected \")\""))?;
+        let (left_value, right_value) = line.split_once(rhs, ", ")?;
+        if value.ends_with('A') {
+            start_a_nodes.push(value.to_string());
+        }
+        references.push((line, left_value));
+        references.push((line, right_value));
+        nodes.insert(
+            value.to_string(),
+            Node {
+                left: left_value.to_string(),
+                right: right_value.to_string(),
+            },
+        );
+    }
+    for (line, name) in references {
+        if !nodes.contains_key(name) {
+            return Err(line.error(name, "unknown node"));
+        }
+    }
+    Ok((nodes, directions, start_a_nodes))
+}
+
+/// Steps from AAA to ZZZ, or `None` if there is no AAA or the walk comes
+/// back round to a (node, direction index) state it has been in before
+/// reaching ZZZ.
+fn parse_nodes(nodes: &HashMap<String, Node>, directions: &[Direction]) -> Option<u64> {
+    let mut seen: HashSet<(&str, usize)> = HashSet::new();
+    let mut current_node = "AAA";
+    let mut count: u64 = 0;
+    while current_node != "ZZZ" {
+        let idx = count as usize % directions.len();
+        if !seen.insert((current_node, idx)) {
+            return None;
+        }
+        let node = nodes.get(current_node)?;
+        current_node = match directions[idx] {
+            Direction::Left => &node.left,
+            Direction::Right => &node.right,
+        };
+        count += 1;
+    }
+    Some(count)
+}
+
+/// Where a ghost is after each step: the steps it takes before entering its
+/// loop of (node, direction index) states, the loop length, and every step
+/// count before the loop closes at which it stands on a Z node.
+struct Cycle {
+    start: u64,
+    length: u64,
+    hits: HashSet<u64>,
+}
+
+impl Cycle {
+    fn is_hit(&self, steps: u64) -> bool {
+        if steps < self.start {
+            return self.hits.contains(&steps);
+        }
+        let offset = (steps - self.start) % self.length;
+        self.hits.contains(&(self.start + offset))
+    }
+}
+
+fn find_cycle(nodes: &HashMap<String, Node>, directions: &[Direction], start: &str) -> Cycle {
+    let mut seen: HashMap<(&str, usize), u64> = HashMap::new();
+    let mut hits: HashSet<u64> = HashSet::new();
+    let mut current_node = start;
+    let mut count: u64 = 0;
+    loop {
+        let idx = count as usize % directions.len();
+        if let Some(first) = seen.insert((current_node, idx), count) {
+            return Cycle {
+                start: first,
+                length: count - first,
+                hits,
+            };
+        }
+        if current_node.ends_with('Z') {
+            hits.insert(count);
+        }
+        let node = nodes.get(current_node).unwrap();
+        current_node = match directions[idx] {
+            Direction::Left => &node.left,
+            Direction::Right => &node.right,
+        };
+        count += 1;
+    }
+}
+
+/// The first step count at which every ghost stands on a Z node at once.
+///
+/// Before all ghosts have entered their loops the steps are checked one by
+/// one; after that the ghosts' in-loop hits are combined ghost by ghost with
+/// the CRT, so no assumption is made about the first Z hit being the loop
+/// length.
+fn parse_nodes_2(
+    nodes: &HashMap<String, Node>,
+    directions: &[Direction],
+    start_nodes: &[String],
+) -> Option<u64> {
+    let cycles: Vec<Cycle> = start_nodes
+        .iter()
+        .map(|x| find_cycle(nodes, directions, x))
+        .collect();
+    if cycles.is_empty() {
+        return None;
+    }
+
+    let prefix = cycles.iter().map(|x| x.start).max().unwrap();
+    if let Some(steps) = (0..prefix).find(|x| cycles.iter().all(|c| c.is_hit(*x))) {
+        return Some(steps);
+    }
+
+    // Combine one ghost at a time: the solutions so far, as (residue,
+    // modulus), merged with each of the next ghost's in-loop hits. Systems
+    // with no solution drop out straight away and equal ones are merged
+    let mut solutions: HashSet<(i64, i64)> = HashSet::from([(0, 1)]);
+    for cycle in &cycles {
+        let residues: Vec<(i64, i64)> = cycle
+            .hits
+            .iter()
+            .filter(|x| **x >= cycle.start)
+            .map(|x| ((x % cycle.length) as i64, cycle.length as i64))
+            .collect();
+        solutions = solutions
+            .iter()
+            .flat_map(|x| residues.iter().filter_map(|y| math::crt([*x, *y])))
+            .collect();
+    }
+
+    solutions
+        .into_iter()
+        .map(|(residue, modulus)| {
+            let (residue, modulus) = (residue as u64, modulus as u64);
+            // Smallest solution at or after the point every ghost is looping
+            if residue >= prefix {
+                residue
+            } else {
+                residue + (prefix - residue).div_ceil(modulus) * modulus
+            }
+        })
+        .min()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (nodes, directions, start_nodes) = generate_nodes_hash_map(input)?;
+        Ok(Network {
+            nodes,
+            directions,
+            start_nodes,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        match parse_nodes(&input.nodes, &input.directions) {
+            Some(x) => x.into(),
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        match parse_nodes_2(&input.nodes, &input.directions, &input.start_nodes) {
+            Some(x) => x.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+utils::example_tests!(crate::Day8, 8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The puzzle's part 2 example, which has no AAA node
+    const GHOSTS: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+    #[test]
+    fn part1_without_aaa() {
+        let network = Day8::parse(GHOSTS).unwrap();
+        assert_eq!(Day8::part1(&network), Answer::Unsolved);
+        assert_eq!(Day8::part2(&network), Answer::from(6_u64));
+    }
+
+    #[test]
+    fn part1_never_reaching_zzz() {
+        let network = Day8::parse("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)").unwrap();
+        assert_eq!(Day8::part1(&network), Answer::Unsolved);
+    }
+
+    #[test]
+    fn part1_loop_depends_on_direction() {
+        // AAA is visited twice, at different points in the directions
+        let network =
+            Day8::parse("LLR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(Day8::part1(&network), Answer::from(3_u64));
+    }
+}
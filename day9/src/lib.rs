@@ -1,20 +1,20 @@
-use utils::load;
+use utils::{parse, Answer, Result, Solution};
 
-fn parse_data(data: String) -> Vec<Vec<i64>> {
-    let inputs: Vec<Vec<i64>> = data
-        .lines()
+const DAY: u32 = 9;
+
+fn parse_data(data: &str) -> Result<Vec<Vec<i64>>> {
+    parse::lines(DAY, data)
         .map(|line| {
-            let split: Vec<i64> = line
-                .split(" ")
-                .map(|num_str| num_str.parse::<i64>().unwrap())
-                .collect();
-            split
+            let split: Vec<i64> = line.numbers(line.text)?;
+            if split.is_empty() {
+                return Err(line.error(line.text, "expected a sequence of numbers"));
+            }
+            Ok(split)
         })
-        .collect::<Vec<Vec<i64>>>();
-    inputs
+        .collect()
 }
 
-fn all_zero(nums: &Vec<i64>) -> bool {
+fn all_zero(nums: &[i64]) -> bool {
     for num in nums {
         if *num != 0 {
             return false;
@@ -23,7 +23,7 @@ fn all_zero(nums: &Vec<i64>) -> bool {
     true
 }
 
-fn get_score_part_1(input: &Vec<i64>) -> i64 {
+fn get_score_part_1(input: &[i64]) -> i64 {
     let mut head_idx = 0;
     let mut state: Vec<Vec<i64>> = vec![input.to_vec()];
 
@@ -44,13 +44,13 @@ fn get_score_part_1(input: &Vec<i64>) -> i64 {
     for idx in (0..state.len()).rev() {
         let current_head = &state[idx];
         let length = current_head.len();
-        prev_new_val = current_head[length - 1] + prev_new_val;
+        prev_new_val += current_head[length - 1];
     }
-    let score = prev_new_val;
-    score
+
+    prev_new_val
 }
 
-fn get_score_part_2(input: &Vec<i64>) -> i64 {
+fn get_score_part_2(input: &[i64]) -> i64 {
     let mut head_idx = 0;
     let mut state: Vec<Vec<i64>> = vec![input.to_vec()];
 
@@ -73,13 +73,13 @@ fn get_score_part_2(input: &Vec<i64>) -> i64 {
     let mut prev_new_val: i64 = 0;
     for idx in (0..state.len()).rev() {
         let current_head = &state[idx];
-        prev_new_val = current_head[0] + prev_new_val;
+        prev_new_val += current_head[0];
     }
-    let score = prev_new_val;
-    score
+
+    prev_new_val
 }
 
-fn part2(inputs: &Vec<Vec<i64>>) -> i64 {
+fn part2(inputs: &[Vec<i64>]) -> i64 {
     let total: i64 = inputs
         .iter()
         .map(|nums| -> i64 { get_score_part_2(nums) })
@@ -87,7 +87,7 @@ fn part2(inputs: &Vec<Vec<i64>>) -> i64 {
     total
 }
 
-fn part1(inputs: &Vec<Vec<i64>>) -> i64 {
+fn part1(inputs: &[Vec<i64>]) -> i64 {
     let total: i64 = inputs
         .iter()
         .map(|nums| -> i64 { get_score_part_1(nums) })
@@ -95,12 +95,22 @@ fn part1(inputs: &Vec<Vec<i64>>) -> i64 {
     total
 }
 
-fn main() {
-    let data = load("input.txt");
-    let inputs = parse_data(data);
-    let part_1_result = part1(&inputs);
-    println!("Result for Part 1 is: {}", part_1_result);
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_data(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
 
-    let part_2_result = part2(&inputs);
-    println!("Result for Part 2 is: {}", part_2_result);
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
 }
+
+utils::example_tests!(crate::Day9, 9);
@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+
+use crate::parse;
+use crate::{Answer, Result};
+
+/// Name of the per-day file holding known answers, next to the inputs.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The known answers for one input file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u32) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn matches(&self, part: u32, answer: &Answer) -> Option<bool> {
+        self.part(part).map(|x| x == answer.to_string())
+    }
+}
+
+/// Known answers for a day, keyed by input file stem (`[input]`, `[test]`).
+///
+/// Only the small subset of TOML the answers files use is understood:
+/// `[section]` headers, `part1`/`part2` keys with integer or string values,
+/// blank lines and `#` comments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    inputs: BTreeMap<String, Expected>,
+}
+
+impl Answers {
+    pub fn parse(day: u32, text: &str) -> Result<Answers> {
+        let mut inputs: BTreeMap<String, Expected> = BTreeMap::new();
+        let mut current: Option<String> = None;
+
+        for line in parse::lines(day, text) {
+            let content = match line.text.split_once('#') {
+                Some((x, _)) => x,
+                None => line.text,
+            }
+            .trim();
+            if content.is_empty() {
+                continue;
+            }
+
+            if let Some(section) = content.strip_prefix('[') {
+                let section = section
+                    .strip_suffix(']')
+                    .ok_or_else(|| line.error(content, "expected \"]\""))?;
+                let name = section.trim().trim_matches('"').to_string();
+                inputs.entry(name.clone()).or_default();
+                current = Some(name);
+                continue;
+            }
+
+            let section = current
+                .as_ref()
+                .ok_or_else(|| line.error(content, "expected a [section] header"))?;
+            let (key, value) = line.split_once(content, "=")?;
+            let value = value.trim();
+            let value = match value.strip_prefix('"') {
+                Some(x) => x
+                    .strip_suffix('"')
+                    .ok_or_else(|| line.error(value, "unterminated string"))?,
+                None => {
+                    line.parse::<i64>(value)?;
+                    value
+                }
+            };
+
+            let expected = inputs.get_mut(section).unwrap();
+            match key.trim() {
+                "part1" => expected.part1 = Some(value.to_string()),
+                "part2" => expected.part2 = Some(value.to_string()),
+                _ => return Err(line.error(key.trim(), "expected part1 or part2")),
+            }
+        }
+        Ok(Answers { inputs })
+    }
+
+    /// Input file stems with recorded answers, in name order.
+    pub fn inputs(&self) -> impl Iterator<Item = (&str, &Expected)> {
+        self.inputs.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
+    pub fn get(&self, input: &str) -> Option<&Expected> {
+        self.inputs.get(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(text: &str) -> (usize, usize, String) {
+        let e = Answers::parse(1, text).unwrap_err();
+        (e.line, e.column, e.message)
+    }
+
+    #[test]
+    fn sections_and_values() {
+        let text = "# known answers\n\n[input]\npart1 = 42 # checked\npart2 = \"abc\"\n\n[\"test2\"]\n  part2=-7\n";
+        let answers = Answers::parse(1, text).unwrap();
+        let input = answers.get("input").unwrap();
+        assert_eq!(input.part(1), Some("42"));
+        assert_eq!(input.part(2), Some("abc"));
+        let test2 = answers.get("test2").unwrap();
+        assert_eq!(test2.part(1), None);
+        assert_eq!(test2.part(2), Some("-7"));
+        let stems: Vec<&str> = answers.inputs().map(|(x, _)| x).collect();
+        assert_eq!(stems, ["input", "test2"]);
+    }
+
+    #[test]
+    fn matches_answers_by_text() {
+        let answers = Answers::parse(1, "[input]\npart1 = 42\npart2 = \"x\"").unwrap();
+        let input = answers.get("input").unwrap();
+        assert_eq!(input.matches(1, &Answer::from(42_u32)), Some(true));
+        assert_eq!(input.matches(1, &Answer::Unsolved), Some(false));
+        assert_eq!(input.matches(2, &Answer::from("x")), Some(true));
+        assert_eq!(input.matches(3, &Answer::from("x")), None);
+    }
+
+    #[test]
+    fn empty_section() {
+        let answers = Answers::parse(1, "[test]\n").unwrap();
+        assert_eq!(answers.get("test"), Some(&Expected::default()));
+        assert_eq!(answers.get("input"), None);
+    }
+
+    #[test]
+    fn unterminated_string() {
+        assert_eq!(
+            error("[input]\npart1 = \"abc"),
+            (2, 9, "unterminated string".to_string())
+        );
+    }
+
+    #[test]
+    fn unknown_key() {
+        assert_eq!(
+            error("[input]\npart3 = 1"),
+            (2, 1, "expected part1 or part2".to_string())
+        );
+    }
+
+    #[test]
+    fn key_before_section() {
+        assert_eq!(
+            error("# header\npart1 = 1"),
+            (2, 1, "expected a [section] header".to_string())
+        );
+    }
+
+    #[test]
+    fn malformed_lines() {
+        assert_eq!(error("[input\n").2, "expected \"]\"");
+        assert_eq!(error("[input]\npart1 = abc").2, "expected a number");
+        assert_eq!(error("[input]\npart1 1").2, "expected \"=\"");
+    }
+}
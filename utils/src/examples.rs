@@ -0,0 +1,74 @@
+use crate::{Answer, Answers, Solution};
+
+/// Solves `part` of a day's example input and checks it against the answers
+/// recorded under `section` (the example file's stem, e.g. `test`). Parts
+/// without a recorded answer must still be unsolved or have failed.
+pub fn check_example<S: Solution>(day: u32, input: &str, answers: &str, section: &str, part: u32) {
+    let answers = Answers::parse(day, answers).unwrap_or_else(|e| panic!("{}", e));
+    let expected = answers.get(section).and_then(|x| x.part(part));
+
+    let parsed = S::parse(input).unwrap_or_else(|e| panic!("{}", e));
+    let answer = match part {
+        1 => S::part1(&parsed),
+        _ => S::part2(&parsed),
+    };
+
+    match expected {
+        Some(x) => assert_eq!(answer.to_string(), x, "day {} part {}", day, part),
+        None => assert!(
+            matches!(answer, Answer::Unsolved | Answer::Failed(_)),
+            "day {} part {} has no recorded {} answer, but gave {}",
+            day,
+            part,
+            section,
+            answer
+        ),
+    }
+}
+
+/// Generates `part1`/`part2` tests running a day's `test.txt` against the
+/// `[test]` section of its `answers.toml`. Days whose parts have separate
+/// examples name each part's file stem, e.g. `example_tests!(Day1, 1, "test", "test2")`.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty, $day:literal) => {
+        $crate::example_tests!($solution, $day, "test", "test");
+    };
+    ($solution:ty, $day:literal, $part1:literal, $part2:literal) => {
+        #[cfg(test)]
+        mod example_tests {
+            const ANSWERS: &str = include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../inputs/day",
+                $day,
+                "/answers.toml"
+            ));
+
+            #[test]
+            fn part1() {
+                let input = include_str!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/../inputs/day",
+                    $day,
+                    "/",
+                    $part1,
+                    ".txt"
+                ));
+                $crate::check_example::<$solution>($day, input, ANSWERS, $part1, 1);
+            }
+
+            #[test]
+            fn part2() {
+                let input = include_str!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/../inputs/day",
+                    $day,
+                    "/",
+                    $part2,
+                    ".txt"
+                ));
+                $crate::check_example::<$solution>($day, input, ANSWERS, $part2, 2);
+            }
+        }
+    };
+}
@@ -0,0 +1,193 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Neg, Sub};
+
+/// A position or offset on a grid. Rows grow downwards, so north is `row - 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { row: 0, col: 0 };
+
+    pub const fn new(row: i64, col: i64) -> Point {
+        Point { row, col }
+    }
+
+    pub fn manhattan(&self, other: Point) -> u64 {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// The neighbouring point one step in `direction`.
+    pub fn step(&self, direction: Direction) -> Point {
+        *self + direction.offset()
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction) -> Point {
+        self.step(direction)
+    }
+}
+
+/// A compass direction, including diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// North, east, south and west, clockwise.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    pub fn offset(&self) -> Point {
+        match self {
+            Direction::North => Point::new(-1, 0),
+            Direction::NorthEast => Point::new(-1, 1),
+            Direction::East => Point::new(0, 1),
+            Direction::SouthEast => Point::new(1, 1),
+            Direction::South => Point::new(1, 0),
+            Direction::SouthWest => Point::new(1, -1),
+            Direction::West => Point::new(0, -1),
+            Direction::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    fn rotate(&self, eighths: usize) -> Direction {
+        let idx = Direction::ALL.iter().position(|x| x == self).unwrap();
+        Direction::ALL[(idx + eighths) % 8]
+    }
+
+    /// Rotates 90 degrees anticlockwise.
+    pub fn turn_left(&self) -> Direction {
+        self.rotate(6)
+    }
+
+    /// Rotates 90 degrees clockwise.
+    pub fn turn_right(&self) -> Direction {
+        self.rotate(2)
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn is_cardinal(&self) -> bool {
+        Direction::CARDINAL.contains(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manhattan_is_symmetric() {
+        let a = Point::new(-2, 3);
+        let b = Point::new(4, -1);
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(b.manhattan(a), 10);
+        assert_eq!(a.manhattan(a), 0);
+    }
+
+    #[test]
+    fn step_follows_offsets() {
+        let p = Point::new(5, 5);
+        assert_eq!(p.step(Direction::North), Point::new(4, 5));
+        assert_eq!(p.step(Direction::SouthWest), Point::new(6, 4));
+        assert_eq!(p + Direction::East, Point::new(5, 6));
+        assert_eq!(-Point::new(1, -2), Point::new(-1, 2));
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::NorthEast.turn_right(), Direction::SouthEast);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        for x in Direction::ALL {
+            assert_eq!(x.turn_left().turn_right(), x);
+            assert_eq!(x.turn_right().turn_right(), x.opposite());
+        }
+    }
+
+    #[test]
+    fn opposite_negates_offset() {
+        for x in Direction::ALL {
+            assert_eq!(x.opposite().offset(), -x.offset());
+            assert_eq!(x.opposite().opposite(), x);
+        }
+    }
+
+    #[test]
+    fn cardinal() {
+        let cardinal: Vec<Direction> = Direction::ALL
+            .into_iter()
+            .filter(|x| x.is_cardinal())
+            .collect();
+        assert_eq!(cardinal, Direction::CARDINAL);
+    }
+}
@@ -0,0 +1,326 @@
+use std::fmt::{Display, Formatter};
+
+use crate::geom::{Direction, Point};
+use crate::parse::{self, ParseError};
+use crate::Result;
+
+/// A rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Panics if `width` is 0 or `cells` does not fill the grid.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(width > 0, "grid width must be positive");
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "grid cells do not fill {}x{}",
+            width,
+            height
+        );
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parses one row per line, mapping each character through `f`. A `None`
+    /// from `f`, ragged rows or an empty input are reported as parse errors.
+    pub fn parse<F>(day: u32, text: &str, mut f: F) -> Result<Grid<T>>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut cells: Vec<T> = vec![];
+        let mut width: Option<usize> = None;
+        let mut height = 0;
+
+        for line in parse::lines(day, text) {
+            let mut row_width = 0;
+            for (idx, ch) in line.text.char_indices() {
+                let cell = f(ch).ok_or_else(|| {
+                    line.error(
+                        &line.text[idx..idx + ch.len_utf8()],
+                        "unrecognised character",
+                    )
+                })?;
+                cells.push(cell);
+                row_width += 1;
+            }
+            match width {
+                Some(x) if x != row_width => {
+                    return Err(line.error(line.text, format!("expected {} columns", x)));
+                }
+                _ => width = Some(row_width),
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid::new(width, height, cells)),
+            _ => Err(parse::unexpected_end(day, text, "a grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cell at `point`, or `None` when it is out of bounds.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index(point).map(|x| &self.cells[x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index(point).map(|x| &mut self.cells[x])
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index(point).is_some()
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        if point.row < 0
+            || point.col < 0
+            || point.row as usize >= self.height
+            || point.col as usize >= self.width
+        {
+            return None;
+        }
+        Some(point.row as usize * self.width + point.col as usize)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        point: Point,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = Point> + 'a {
+        directions
+            .iter()
+            .map(move |x| point.step(*x))
+            .filter(|x| self.contains(*x))
+    }
+
+    /// In-bounds orthogonal neighbours, clockwise from north.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &Direction::CARDINAL)
+    }
+
+    /// In-bounds neighbours including diagonals, clockwise from north.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &Direction::ALL)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// The cells of column `col`, top to bottom. Panics if `col` is out of
+    /// bounds, as [`Grid::row`] does.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            col < self.width,
+            "column {} out of bounds for width {}",
+            col,
+            self.width
+        );
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// Every point with its cell, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().enumerate().map(|(i, x)| {
+            let point = Point::new((i / self.width) as i64, (i % self.width) as i64);
+            (point, x)
+        })
+    }
+
+    /// The first point, in row-major order, whose cell matches `predicate`.
+    pub fn find<P>(&self, mut predicate: P) -> Option<Point>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().find(|(_, x)| predicate(x)).map(|(pos, _)| pos)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A run of decimal digits along one row of a grid, read as a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GridNumber {
+    pub value: u64,
+    pub row: i64,
+    pub col_start: i64,
+    /// One past the last digit's column.
+    pub col_end: i64,
+}
+
+impl GridNumber {
+    /// The cells holding the number's digits, left to right.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (self.col_start..self.col_end).map(|col| Point::new(self.row, col))
+    }
+
+    /// Whether `point` touches one of the number's digits, diagonals included.
+    pub fn is_adjacent(&self, point: Point) -> bool {
+        let touching = point.row.abs_diff(self.row) <= 1
+            && point.col >= self.col_start - 1
+            && point.col <= self.col_end;
+        let inside =
+            point.row == self.row && point.col >= self.col_start && point.col < self.col_end;
+        touching && !inside
+    }
+
+    /// The in-bounds cells around the number, diagonals included, in
+    /// row-major order.
+    pub fn neighbours<'a, T>(&'a self, grid: &'a Grid<T>) -> impl Iterator<Item = Point> + 'a {
+        (self.row - 1..=self.row + 1)
+            .flat_map(move |row| {
+                (self.col_start - 1..=self.col_end).map(move |col| Point::new(row, col))
+            })
+            .filter(move |x| self.is_adjacent(*x) && grid.contains(*x))
+    }
+}
+
+impl Grid<char> {
+    /// Every number on the grid in row-major order. Numbers never run on
+    /// from the end of one row to the start of the next. A number too large
+    /// for a `u64` is reported as a parse error on its row.
+    pub fn numbers(&self, day: u32) -> Result<Vec<GridNumber>> {
+        let mut numbers: Vec<GridNumber> = vec![];
+        for (row, cells) in self.rows().enumerate() {
+            let mut current: Option<GridNumber> = None;
+            for (col, ch) in cells.iter().enumerate() {
+                match (ch.to_digit(10), current.as_mut()) {
+                    (Some(digit), Some(x)) => {
+                        x.col_end += 1;
+                        x.value = x
+                            .value
+                            .checked_mul(10)
+                            .and_then(|v| v.checked_add(digit as u64))
+                            .ok_or_else(|| too_large(day, cells, x))?;
+                    }
+                    (Some(digit), None) => {
+                        current = Some(GridNumber {
+                            value: digit as u64,
+                            row: row as i64,
+                            col_start: col as i64,
+                            col_end: col as i64 + 1,
+                        });
+                    }
+                    (None, _) => numbers.extend(current.take()),
+                }
+            }
+            numbers.extend(current);
+        }
+        Ok(numbers)
+    }
+}
+
+// Points at the whole run of digits `number` starts, not just the part read
+fn too_large(day: u32, cells: &[char], number: &GridNumber) -> ParseError {
+    let digits: String = cells[number.col_start as usize..]
+        .iter()
+        .take_while(|x| x.is_ascii_digit())
+        .collect();
+    ParseError {
+        day,
+        line: number.row as usize + 1,
+        column: number.col_start as usize + 1,
+        text: digits,
+        message: "number too large".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::parse(0, text, Some).unwrap()
+    }
+
+    #[test]
+    fn numbers_stop_at_row_ends() {
+        let numbers = grid("..12\n34.5").numbers(0).unwrap();
+        let found: Vec<(u64, i64, i64, i64)> = numbers
+            .iter()
+            .map(|x| (x.value, x.row, x.col_start, x.col_end))
+            .collect();
+        assert_eq!(found, vec![(12, 0, 2, 4), (34, 1, 0, 2), (5, 1, 3, 4)]);
+    }
+
+    #[test]
+    fn number_neighbours() {
+        let g = grid("....\n.12.\n....");
+        let number = g.numbers(0).unwrap()[0];
+        assert_eq!(number.neighbours(&g).count(), 10);
+        assert!(number.is_adjacent(Point::new(0, 0)));
+        assert!(number.is_adjacent(Point::new(2, 3)));
+        assert!(!number.is_adjacent(Point::new(1, 1)));
+
+        let corner = grid("12.\n...").numbers(0).unwrap()[0];
+        let g = grid("12.\n...");
+        let around: Vec<Point> = corner.neighbours(&g).collect();
+        assert_eq!(
+            around,
+            vec![
+                Point::new(0, 2),
+                Point::new(1, 0),
+                Point::new(1, 1),
+                Point::new(1, 2)
+            ]
+        );
+        assert_eq!(corner.points().count(), 2);
+    }
+
+    #[test]
+    fn column() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.column(2).collect::<String>(), "cf");
+    }
+
+    #[test]
+    #[should_panic]
+    fn column_out_of_bounds() {
+        let _ = grid("abc\ndef").column(3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_width() {
+        Grid::<char>::new(0, 0, vec![]);
+    }
+
+    #[test]
+    fn number_too_large() {
+        assert!(grid("18446744073709551615").numbers(0).is_ok());
+        let e = grid("..18446744073709551616*").numbers(3).unwrap_err();
+        assert_eq!((e.day, e.line, e.column), (3, 1, 3));
+        assert_eq!(e.text, "18446744073709551616");
+    }
+}
@@ -0,0 +1,98 @@
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the directory inputs are looked up in.
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+const DEFAULT_INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs");
+
+/// Where a puzzle input should be read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// A named file for a day inside the inputs directory, e.g. `test.txt`.
+    Named(String),
+    /// An explicit path, used as given.
+    File(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "input not found: {}", path.display()),
+            InputError::Io(path, e) => write!(f, "failed to read {}: {}", path.display(), e),
+            InputError::Stdin(e) => write!(f, "failed to read stdin: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::NotFound(_) => None,
+            InputError::Io(_, e) | InputError::Stdin(e) => Some(e),
+        }
+    }
+}
+
+/// Resolves puzzle inputs laid out as `<dir>/day<N>/<name>`.
+#[derive(Debug, Clone)]
+pub struct InputLocator {
+    dir: PathBuf,
+}
+
+impl InputLocator {
+    pub fn new(dir: impl Into<PathBuf>) -> InputLocator {
+        InputLocator { dir: dir.into() }
+    }
+
+    /// Uses `dir` if given, then `$AOC_INPUTS`, then the workspace `inputs/` directory.
+    pub fn resolve(dir: Option<&Path>) -> InputLocator {
+        match dir {
+            Some(x) => InputLocator::new(x),
+            None => match env::var_os(INPUTS_ENV) {
+                Some(x) => InputLocator::new(x),
+                None => InputLocator::new(DEFAULT_INPUTS_DIR),
+            },
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, day: u32, name: &str) -> PathBuf {
+        self.dir.join(format!("day{}", day)).join(name)
+    }
+
+    pub fn load(&self, day: u32, source: &InputSource) -> Result<String, InputError> {
+        match source {
+            InputSource::Named(name) => read_file(&self.path(day, name)),
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut data = String::new();
+                io::stdin()
+                    .read_to_string(&mut data)
+                    .map_err(InputError::Stdin)?;
+                Ok(data)
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path.to_path_buf()),
+        _ => InputError::Io(path.to_path_buf(), e),
+    })
+}
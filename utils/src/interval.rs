@@ -0,0 +1,494 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Range, Sub};
+
+/// A half-open interval `start..end`, empty when `start >= end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Copy + Ord> Interval<T> {
+    pub const fn new(start: T, end: T) -> Interval<T> {
+        Interval { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.intersect(other).is_some()
+    }
+
+    /// The values in both intervals, or `None` if they share none.
+    pub fn intersect(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        let x = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!x.is_empty()).then_some(x)
+    }
+
+    /// The parts of `self` not in `other`, in order. There are at most two.
+    pub fn subtract(&self, other: &Interval<T>) -> Vec<Interval<T>> {
+        match self.intersect(other) {
+            Some(x) => [
+                Interval::new(self.start, x.start),
+                Interval::new(x.end, self.end),
+            ]
+            .into_iter()
+            .filter(|x| !x.is_empty())
+            .collect(),
+            None if self.is_empty() => vec![],
+            None => vec![*self],
+        }
+    }
+
+    /// The interval covering both, if they overlap or touch.
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if other.is_empty() {
+            return Some(*self);
+        }
+        if self.is_empty() {
+            return Some(*other);
+        }
+        (self.start <= other.end && other.start <= self.end)
+            .then(|| Interval::new(self.start.min(other.start), self.end.max(other.end)))
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Default> Interval<T> {
+    /// Number of values in the interval.
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::default()
+        } else {
+            self.end - self.start
+        }
+    }
+}
+
+impl<T> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Interval<T> {
+        Interval {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/// A set of values kept as disjoint, non-touching intervals sorted by start.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet { intervals: vec![] }
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet::default()
+    }
+
+    /// Adds every value in `interval`, merging it with any it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let mut merged = interval;
+        let mut intervals: Vec<Interval<T>> = vec![];
+        for x in &self.intervals {
+            match merged.union(x) {
+                Some(u) => merged = u,
+                None => intervals.push(*x),
+            }
+        }
+        intervals.push(merged);
+        intervals.sort_by_key(|x| x.start);
+        self.intervals = intervals;
+    }
+
+    /// Removes every value in `interval`.
+    pub fn remove(&mut self, interval: Interval<T>) {
+        self.intervals = self
+            .intervals
+            .iter()
+            .flat_map(|x| x.subtract(&interval))
+            .collect();
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.intervals.iter().any(|x| x.contains(value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of disjoint intervals in the set, not the number of values.
+    pub fn interval_count(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|x| x.start)
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut out = self.clone();
+        for x in &other.intervals {
+            out.insert(*x);
+        }
+        out
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.intervals
+            .iter()
+            .flat_map(|x| other.intervals.iter().filter_map(|y| x.intersect(y)))
+            .collect()
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut out = self.clone();
+        for x in &other.intervals {
+            out.remove(*x);
+        }
+        out
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        for x in iter {
+            set.insert(x);
+        }
+        set
+    }
+}
+
+/// A map on `i64` that adds a constant offset to the values in each of a set
+/// of disjoint intervals and leaves every other value unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    // Sorted by start and disjoint
+    pieces: Vec<(Interval<i64>, i64)>,
+}
+
+impl PiecewiseMap {
+    pub fn new() -> PiecewiseMap {
+        PiecewiseMap::default()
+    }
+
+    /// Adds `offset` on the values of `domain` that no existing piece covers,
+    /// so where pieces overlap the first one inserted wins.
+    pub fn insert(&mut self, domain: Interval<i64>, offset: i64) {
+        let mut remaining: Vec<Interval<i64>> = vec![domain];
+        for (x, _) in &self.pieces {
+            remaining = remaining.iter().flat_map(|y| y.subtract(x)).collect();
+        }
+        self.pieces.extend(
+            remaining
+                .into_iter()
+                .filter(|x| !x.is_empty())
+                .map(|x| (x, offset)),
+        );
+        self.pieces.sort_by_key(|(x, _)| x.start);
+    }
+
+    /// The pieces as `(domain, offset)`, sorted by domain.
+    pub fn pieces(&self) -> impl Iterator<Item = (Interval<i64>, i64)> + '_ {
+        self.pieces.iter().copied()
+    }
+
+    /// Values where the offset changes: every piece start and end, in order.
+    pub fn breakpoints(&self) -> Vec<i64> {
+        let mut out: Vec<i64> = vec![];
+        for (x, _) in &self.pieces {
+            if out.last() != Some(&x.start) {
+                out.push(x.start);
+            }
+            out.push(x.end);
+        }
+        out
+    }
+
+    pub fn apply(&self, value: i64) -> i64 {
+        let offset = self
+            .pieces
+            .iter()
+            .find(|(x, _)| x.contains(value))
+            .map_or(0, |(_, offset)| *offset);
+        value + offset
+    }
+
+    /// Every piece plus the identity gaps between them, covering all of
+    /// `i64::MIN..i64::MAX` in order. The flag marks real pieces.
+    fn segments(&self) -> Vec<(Interval<i64>, i64, bool)> {
+        let mut out: Vec<(Interval<i64>, i64, bool)> = vec![];
+        let mut current = i64::MIN;
+        for (x, offset) in &self.pieces {
+            if x.start > current {
+                out.push((Interval::new(current, x.start), 0, false));
+            }
+            out.push((*x, *offset, true));
+            current = x.end;
+        }
+        if current < i64::MAX {
+            out.push((Interval::new(current, i64::MAX), 0, false));
+        }
+        out
+    }
+
+    /// The image of `interval`, split wherever it crosses a piece boundary.
+    pub fn map_interval(&self, interval: Interval<i64>) -> IntervalSet<i64> {
+        self.segments()
+            .iter()
+            .filter_map(|(x, offset, _)| {
+                x.intersect(&interval)
+                    .map(|y| Interval::new(y.start + offset, y.end + offset))
+            })
+            .collect()
+    }
+
+    pub fn map_set(&self, set: &IntervalSet<i64>) -> IntervalSet<i64> {
+        let mut out = IntervalSet::new();
+        for x in set.iter() {
+            out = out.union(&self.map_interval(*x));
+        }
+        out
+    }
+
+    /// Every value that maps into `set`. This inverts the map even where
+    /// pieces overlap in their images, so one value may have many sources.
+    pub fn preimage(&self, set: &IntervalSet<i64>) -> IntervalSet<i64> {
+        let mut out = IntervalSet::new();
+        for (x, offset, _) in self.segments() {
+            for y in set.iter() {
+                let source = Interval::new(y.start - offset, y.end - offset);
+                if let Some(z) = x.intersect(&source) {
+                    out.insert(z);
+                }
+            }
+        }
+        out
+    }
+
+    /// The map applying `self` and then `other`, as a single piecewise map.
+    pub fn then(&self, other: &PiecewiseMap) -> PiecewiseMap {
+        let others = other.segments();
+        let mut pieces: Vec<(Interval<i64>, i64)> = vec![];
+        for (x, offset, is_piece) in self.segments() {
+            let image = Interval::new(x.start + offset, x.end + offset);
+            for (y, other_offset, other_is_piece) in &others {
+                if !is_piece && !other_is_piece {
+                    continue;
+                }
+                let Some(part) = image.intersect(y) else {
+                    continue;
+                };
+                let domain = Interval::new(part.start - offset, part.end - offset);
+                let offset = offset + other_offset;
+                match pieces.last_mut() {
+                    Some((last, last_offset))
+                        if last.end == domain.start && *last_offset == offset =>
+                    {
+                        last.end = domain.end;
+                    }
+                    _ => pieces.push((domain, offset)),
+                }
+            }
+        }
+        PiecewiseMap { pieces }
+    }
+}
+
+/// One piece per line, as `[start, end) -> [start, end) (+offset)`.
+impl Display for PiecewiseMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (x, offset) in &self.pieces {
+            let image = Interval::new(x.start + offset, x.end + offset);
+            writeln!(f, "{} -> {} ({:+})", x, image, offset)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromIterator<(Interval<i64>, i64)> for PiecewiseMap {
+    fn from_iter<I: IntoIterator<Item = (Interval<i64>, i64)>>(iter: I) -> PiecewiseMap {
+        let mut map = PiecewiseMap::new();
+        for (domain, offset) in iter {
+            map.insert(domain, offset);
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet<i64> {
+        intervals
+            .iter()
+            .map(|(a, b)| Interval::new(*a, *b))
+            .collect()
+    }
+
+    fn intervals(set: &IntervalSet<i64>) -> Vec<(i64, i64)> {
+        set.iter().map(|x| (x.start, x.end)).collect()
+    }
+
+    #[test]
+    fn interval_ops() {
+        let a = Interval::new(0, 10);
+        assert_eq!(a.len(), 10);
+        assert_eq!(Interval::new(5, 3).len(), 0);
+        assert_eq!(
+            a.intersect(&Interval::new(5, 15)),
+            Some(Interval::new(5, 10))
+        );
+        assert_eq!(a.intersect(&Interval::new(10, 15)), None);
+        assert_eq!(
+            a.subtract(&Interval::new(3, 5)),
+            vec![Interval::new(0, 3), Interval::new(5, 10)]
+        );
+        assert_eq!(a.subtract(&Interval::new(-5, 20)), vec![]);
+        assert_eq!(a.subtract(&Interval::new(20, 30)), vec![a]);
+        assert_eq!(a.union(&Interval::new(10, 12)), Some(Interval::new(0, 12)));
+        assert_eq!(a.union(&Interval::new(11, 12)), None);
+    }
+
+    #[test]
+    fn insert_merges_overlapping_and_touching() {
+        let mut x = set(&[(0, 5), (10, 15)]);
+        assert_eq!(x.interval_count(), 2);
+        x.insert(Interval::new(5, 7));
+        assert_eq!(intervals(&x), vec![(0, 7), (10, 15)]);
+        x.insert(Interval::new(6, 11));
+        assert_eq!(intervals(&x), vec![(0, 15)]);
+        x.insert(Interval::new(-10, -5));
+        x.insert(Interval::new(3, 3));
+        assert_eq!(intervals(&x), vec![(-10, -5), (0, 15)]);
+        assert_eq!(x.min(), Some(-10));
+    }
+
+    #[test]
+    fn remove_splits() {
+        let mut x = set(&[(0, 10), (20, 30)]);
+        x.remove(Interval::new(5, 25));
+        assert_eq!(intervals(&x), vec![(0, 5), (25, 30)]);
+        x.remove(Interval::new(0, 5));
+        assert_eq!(intervals(&x), vec![(25, 30)]);
+        assert!(x.contains(29) && !x.contains(30) && !x.contains(0));
+        x.remove(Interval::new(0, 100));
+        assert!(x.is_empty());
+        assert_eq!(x.min(), None);
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (40, 50)]);
+        assert_eq!(intervals(&a.union(&b)), vec![(0, 30), (40, 50)]);
+        assert_eq!(intervals(&a.intersection(&b)), vec![(5, 10), (20, 25)]);
+        assert_eq!(intervals(&a.difference(&b)), vec![(0, 5), (25, 30)]);
+        assert_eq!(intervals(&b.difference(&a)), vec![(10, 20), (40, 50)]);
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn piecewise_first_insert_wins() {
+        let mut map = PiecewiseMap::new();
+        map.insert(Interval::new(0, 10), 100);
+        map.insert(Interval::new(5, 15), -5);
+        assert_eq!(map.apply(4), 104);
+        assert_eq!(map.apply(5), 105);
+        assert_eq!(map.apply(10), 5);
+        assert_eq!(map.apply(15), 15);
+        assert_eq!(map.apply(-1), -1);
+        assert_eq!(map.breakpoints(), vec![0, 10, 15]);
+    }
+
+    fn sample() -> (PiecewiseMap, PiecewiseMap) {
+        // The first two stages of the day 5 example, seed to fertilizer
+        let first: PiecewiseMap = [(Interval::new(98, 100), -48), (Interval::new(50, 98), 2)]
+            .into_iter()
+            .collect();
+        let second: PiecewiseMap = [
+            (Interval::new(15, 52), -15),
+            (Interval::new(52, 54), -15),
+            (Interval::new(0, 15), 39),
+        ]
+        .into_iter()
+        .collect();
+        (first, second)
+    }
+
+    #[test]
+    fn then_composes_pointwise() {
+        let (first, second) = sample();
+        let composed = first.then(&second);
+        for x in -10..120 {
+            assert_eq!(composed.apply(x), second.apply(first.apply(x)), "at {}", x);
+        }
+        // Composing with the identity changes nothing
+        assert_eq!(first.then(&PiecewiseMap::new()), first);
+        assert_eq!(PiecewiseMap::new().then(&first), first);
+    }
+
+    #[test]
+    fn map_set_matches_apply() {
+        let (first, _) = sample();
+        let image = first.map_set(&set(&[(90, 105)]));
+        for x in 90..105 {
+            assert!(image.contains(first.apply(x)), "at {}", x);
+        }
+        assert_eq!(intervals(&image), vec![(50, 52), (92, 105)]);
+    }
+
+    #[test]
+    fn preimage_inverts() {
+        let (first, second) = sample();
+        let composed = first.then(&second);
+        let target = set(&[(0, 20), (45, 60)]);
+        let sources = composed.preimage(&target);
+        for x in -10..120 {
+            assert_eq!(
+                sources.contains(x),
+                target.contains(composed.apply(x)),
+                "at {}",
+                x
+            );
+        }
+        // Stage by stage, backwards, gives the same sources
+        let staged = first.preimage(&second.preimage(&target));
+        assert_eq!(staged, sources);
+    }
+
+    #[test]
+    fn preimage_with_overlapping_images() {
+        // 0..5 and 10..15 both land on 10..15
+        let map: PiecewiseMap = [(Interval::new(0, 5), 10)].into_iter().collect();
+        let sources = map.preimage(&set(&[(10, 12)]));
+        assert_eq!(intervals(&sources), vec![(0, 2), (10, 12)]);
+    }
+}
@@ -1,6 +1,22 @@
-use std::fs;
+mod answers;
+mod examples;
+pub mod geom;
+pub mod grid;
+mod input;
+pub mod interval;
+pub mod math;
+mod options;
+pub mod parse;
+pub mod scanner;
+mod solution;
 
-pub fn load(path: &str) -> String {
-    let new_path = format!("src/{}", path);
-    fs::read_to_string(new_path).expect("Failed to read file")
-}
+pub use answers::{Answers, Expected, ANSWERS_FILE};
+pub use examples::check_example;
+pub use geom::{Direction, Point};
+pub use grid::{Grid, GridNumber};
+pub use input::{InputError, InputLocator, InputSource, INPUTS_ENV};
+pub use interval::{Interval, IntervalSet, PiecewiseMap};
+pub use options::Options;
+pub use parse::ParseError;
+pub use scanner::Scanner;
+pub use solution::{Answer, ReportFormat, Result, Solution};
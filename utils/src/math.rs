@@ -0,0 +1,174 @@
+/// Greatest common divisor; `gcd(0, 0)` is 0.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, or `None` if it does not fit in a `u64`.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+pub fn gcd_all<I: IntoIterator<Item = u64>>(values: I) -> u64 {
+    values.into_iter().fold(0, gcd)
+}
+
+/// Least common multiple of every value (1 for none), or `None` on overflow.
+pub fn lcm_all<I: IntoIterator<Item = u64>>(values: I) -> Option<u64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// Returns `(g, x, y)` with `g = gcd(a, b)` and `a * x + b * y = g`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The inverse of `a` modulo `m` in `0..m`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// `base.pow(exp) % m` without intermediate overflow.
+pub fn mod_pow(base: i64, mut exp: u64, m: i64) -> i64 {
+    assert!(m > 0, "modulus must be positive");
+    let m = m as i128;
+    let mut base = (base as i128).rem_euclid(m);
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result as i64
+}
+
+/// Solves a system of congruences `x = r (mod m)` given as `(r, m)` pairs.
+///
+/// The moduli need not be coprime. Returns the smallest non-negative solution
+/// together with the combined modulus (the lcm of the moduli), or `None` if
+/// the congruences are inconsistent, a modulus is not positive, or the
+/// combined modulus does not fit in an `i64`.
+pub fn crt<I: IntoIterator<Item = (i64, i64)>>(congruences: I) -> Option<(i64, i64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            if m2 <= 0 {
+                return None;
+            }
+            let r2 = r2.rem_euclid(m2);
+            let (g, p, _) = extended_gcd(m1, m2);
+            if (r2 - r1) % g != 0 {
+                return None;
+            }
+            // m1 * p = g (mod m2), so p inverts m1 / g modulo m2 / g
+            let step = (m2 / g) as i128;
+            let k = ((r2 - r1) / g) as i128 * p as i128 % step;
+            let modulus = i64::try_from(m1 as i128 * step).ok()?;
+            let r = (r1 as i128 + m1 as i128 * k).rem_euclid(modulus as i128);
+            Some((r as i64, modulus))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(gcd_all([]), 0);
+        assert_eq!(lcm_all([2, 3, 4]), Some(12));
+        assert_eq!(lcm_all([]), Some(1));
+    }
+
+    #[test]
+    fn lcm_overflow() {
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+        assert_eq!(lcm_all([1 << 63, 3]), None);
+    }
+
+    #[test]
+    fn extended_gcd_identity() {
+        for (a, b) in [(240, 46), (-240, 46), (240, -46), (0, 5), (7, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i64);
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(14, 11), Some(4));
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(mod_inverse(3, 0), None);
+        assert_eq!(mod_inverse(3, -11), None);
+    }
+
+    #[test]
+    fn pow() {
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(-2, 3, 5), 2);
+        assert_eq!(mod_pow(7, 0, 1), 0);
+        assert_eq!(mod_pow(i64::MAX, 2, i64::MAX - 1), 1);
+    }
+
+    #[test]
+    fn crt_coprime() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(-1, 4), (0, 3)]), Some((3, 12)));
+        assert_eq!(crt([]), Some((0, 1)));
+    }
+
+    #[test]
+    fn crt_not_coprime() {
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(3, 6), (3, 6)]), Some((3, 6)));
+    }
+
+    #[test]
+    fn crt_bad_moduli() {
+        assert_eq!(crt([(1, 0)]), None);
+        assert_eq!(crt([(1, -3)]), None);
+    }
+
+    #[test]
+    fn crt_overflow() {
+        let big = (1 << 62) - 57;
+        assert_eq!(crt([(0, big), (1, 3)]).map(|(_, m)| m), None);
+        let (r, m) = crt([(5, big), (5, big)]).unwrap();
+        assert_eq!((r, m), (5, big));
+    }
+}
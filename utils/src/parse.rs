@@ -0,0 +1,114 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A malformed input, located by day, 1-based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u32,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Errors in options rather than the input have no line to point at
+        if self.line == 0 {
+            return write!(
+                f,
+                "day {}: {} (found {:?})",
+                self.day, self.message, self.text
+            );
+        }
+        write!(
+            f,
+            "day {}, line {}, column {}: {} (found {:?})",
+            self.day, self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A single input line, used to build located errors for slices of it.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: u32,
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Iterates the lines of `input` with their 1-based line numbers.
+pub fn lines(day: u32, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(move |(i, text)| Line {
+        day,
+        number: i + 1,
+        text,
+    })
+}
+
+/// Error for input that ends before `what` was found.
+pub fn unexpected_end(day: u32, input: &str, what: &str) -> ParseError {
+    ParseError {
+        day,
+        line: input.lines().count() + 1,
+        column: 1,
+        text: String::new(),
+        message: format!("unexpected end of input, expected {}", what),
+    }
+}
+
+/// Error for a malformed `key` option.
+pub fn invalid_option(day: u32, key: &str, value: &str, message: &str) -> ParseError {
+    ParseError {
+        day,
+        line: 0,
+        column: 0,
+        text: value.to_string(),
+        message: format!("invalid {} option, {}", key, message),
+    }
+}
+
+impl<'a> Line<'a> {
+    /// Builds an error pointing at `at`, which should be a slice of this line.
+    pub fn error(&self, at: &str, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column: self.column_of(at),
+            text: at.to_string(),
+            message: message.into(),
+        }
+    }
+
+    fn column_of(&self, at: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.text.len() {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+
+    pub fn split_once(&self, s: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(sep)
+            .ok_or_else(|| self.error(s, format!("expected {:?}", sep)))
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("expected {:?}", prefix)))
+    }
+
+    pub fn parse<T: FromStr>(&self, s: &'a str) -> Result<T, ParseError> {
+        s.parse::<T>()
+            .map_err(|_| self.error(s, "expected a number"))
+    }
+
+    /// Parses every whitespace-separated token of `s`.
+    pub fn numbers<T: FromStr>(&self, s: &'a str) -> Result<Vec<T>, ParseError> {
+        s.split_whitespace().map(|x| self.parse(x)).collect()
+    }
+}
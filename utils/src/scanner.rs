@@ -0,0 +1,241 @@
+use std::collections::{HashMap, VecDeque};
+
+/// An occurrence of a pattern, as a byte range of the scanned text. Both ends
+/// fall on char boundaries, so `&text[start..end]` is always valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'a, V> {
+    pub start: usize,
+    pub end: usize,
+    pub value: &'a V,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    next: HashMap<u8, usize>,
+    fail: usize,
+    // Patterns ending here, including those ending at nodes down the fail chain
+    outputs: Vec<usize>,
+}
+
+/// Finds every occurrence of a fixed set of patterns, overlapping ones
+/// included, in a single pass over the text (an Aho–Corasick automaton).
+/// Each pattern carries a value that is handed back with its matches.
+#[derive(Debug, Clone)]
+pub struct Scanner<V> {
+    nodes: Vec<Node>,
+    patterns: Vec<(usize, V)>,
+    ignore_case: bool,
+}
+
+impl<V> Scanner<V> {
+    pub fn new<I, P>(patterns: I) -> Scanner<V>
+    where
+        I: IntoIterator<Item = (P, V)>,
+        P: AsRef<str>,
+    {
+        Scanner::build(patterns, false)
+    }
+
+    /// A scanner matching regardless of case, using Unicode lowercasing on
+    /// both the patterns and the text.
+    pub fn new_ignore_case<I, P>(patterns: I) -> Scanner<V>
+    where
+        I: IntoIterator<Item = (P, V)>,
+        P: AsRef<str>,
+    {
+        Scanner::build(patterns, true)
+    }
+
+    fn build<I, P>(patterns: I, ignore_case: bool) -> Scanner<V>
+    where
+        I: IntoIterator<Item = (P, V)>,
+        P: AsRef<str>,
+    {
+        let mut scanner = Scanner {
+            nodes: vec![Node::default()],
+            patterns: vec![],
+            ignore_case,
+        };
+        for (pattern, value) in patterns {
+            let pattern = match ignore_case {
+                true => pattern.as_ref().to_lowercase(),
+                false => pattern.as_ref().to_string(),
+            };
+            scanner.insert(pattern.as_bytes(), value);
+        }
+        scanner.link();
+        scanner
+    }
+
+    fn insert(&mut self, pattern: &[u8], value: V) {
+        if pattern.is_empty() {
+            return;
+        }
+        let mut current = 0;
+        for byte in pattern {
+            current = match self.nodes[current].next.get(byte) {
+                Some(x) => *x,
+                None => {
+                    self.nodes.push(Node::default());
+                    let idx = self.nodes.len() - 1;
+                    self.nodes[current].next.insert(*byte, idx);
+                    idx
+                }
+            };
+        }
+        self.nodes[current].outputs.push(self.patterns.len());
+        self.patterns.push((pattern.len(), value));
+    }
+
+    // Breadth first so every node's fail target is finished before its children
+    fn link(&mut self) {
+        let mut queue: VecDeque<usize> = self.nodes[0].next.values().copied().collect();
+        while let Some(current) = queue.pop_front() {
+            let edges: Vec<(u8, usize)> = self.nodes[current]
+                .next
+                .iter()
+                .map(|(k, v)| (*k, *v))
+                .collect();
+            for (byte, child) in edges {
+                let fail = self.step(self.nodes[current].fail, byte);
+                self.nodes[child].fail = fail;
+                let inherited = self.nodes[fail].outputs.clone();
+                self.nodes[child].outputs.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+    }
+
+    fn step(&self, mut state: usize, byte: u8) -> usize {
+        loop {
+            if let Some(x) = self.nodes[state].next.get(&byte) {
+                return *x;
+            }
+            if state == 0 {
+                return 0;
+            }
+            state = self.nodes[state].fail;
+        }
+    }
+
+    /// Every match in `text`, ordered by where it ends. Matches ending at
+    /// the same place come longest first.
+    pub fn find_iter<'a>(&'a self, text: &'a str) -> Matches<'a, V> {
+        // The bytes fed to the automaton, each with the span of the char of
+        // `text` it came from, since lowercasing can change a char's length
+        let mut bytes: Vec<(u8, usize, usize)> = Vec::with_capacity(text.len());
+        for (start, ch) in text.char_indices() {
+            let end = start + ch.len_utf8();
+            let mut buf = [0; 4];
+            if self.ignore_case {
+                for lower in ch.to_lowercase() {
+                    bytes.extend(lower.encode_utf8(&mut buf).bytes().map(|x| (x, start, end)));
+                }
+            } else {
+                bytes.extend(ch.encode_utf8(&mut buf).bytes().map(|x| (x, start, end)));
+            }
+        }
+        Matches {
+            scanner: self,
+            bytes,
+            pos: 0,
+            state: 0,
+            output: 0,
+        }
+    }
+}
+
+/// Iterator over a [`Scanner`]'s matches in a text.
+pub struct Matches<'a, V> {
+    scanner: &'a Scanner<V>,
+    bytes: Vec<(u8, usize, usize)>,
+    pos: usize,
+    state: usize,
+    output: usize,
+}
+
+impl<'a, V> Iterator for Matches<'a, V> {
+    type Item = Match<'a, V>;
+
+    fn next(&mut self) -> Option<Match<'a, V>> {
+        loop {
+            let outputs = &self.scanner.nodes[self.state].outputs;
+            if self.output < outputs.len() {
+                let (len, value) = &self.scanner.patterns[outputs[self.output]];
+                self.output += 1;
+                let (_, start, _) = self.bytes[self.pos - len];
+                let (_, _, end) = self.bytes[self.pos - 1];
+                return Some(Match { start, end, value });
+            }
+            let (byte, _, _) = *self.bytes.get(self.pos)?;
+            self.state = self.scanner.step(self.state, byte);
+            self.pos += 1;
+            self.output = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found<'a>(scanner: &'a Scanner<&str>, text: &'a str) -> Vec<(usize, usize, &'a str)> {
+        scanner
+            .find_iter(text)
+            .map(|x| (x.start, x.end, *x.value))
+            .collect()
+    }
+
+    #[test]
+    fn overlapping_patterns() {
+        let scanner = Scanner::new(["he", "she", "his", "hers"].map(|x| (x, x)));
+        assert_eq!(
+            found(&scanner, "ushers"),
+            vec![(1, 4, "she"), (2, 4, "he"), (2, 6, "hers")]
+        );
+        assert_eq!(
+            found(&scanner, "hishe"),
+            vec![(0, 3, "his"), (2, 5, "she"), (3, 5, "he")]
+        );
+        assert_eq!(found(&scanner, "xyz"), vec![]);
+    }
+
+    #[test]
+    fn overlapping_number_words() {
+        let scanner = Scanner::new([("one", 1), ("two", 2), ("eight", 8)]);
+        let matches: Vec<(usize, u32)> = scanner
+            .find_iter("eightwone")
+            .map(|x| (x.start, *x.value))
+            .collect();
+        assert_eq!(matches, vec![(0, 8), (4, 2), (6, 1)]);
+    }
+
+    #[test]
+    fn repeated_and_empty_patterns() {
+        let scanner = Scanner::new([("", "empty"), ("aa", "aa")]);
+        assert_eq!(found(&scanner, "aaa"), vec![(0, 2, "aa"), (1, 3, "aa")]);
+    }
+
+    #[test]
+    fn case_sensitive_by_default() {
+        let scanner = Scanner::new([("fünf", "5")]);
+        assert_eq!(found(&scanner, "FÜNF fünf"), vec![(6, 11, "5")]);
+    }
+
+    #[test]
+    fn ignore_case_reports_original_offsets() {
+        let scanner = Scanner::new_ignore_case([("FÜNF", "5"), ("zwei", "2")]);
+        let text = "xFünFZWEI";
+        assert_eq!(found(&scanner, text), vec![(1, 6, "5"), (6, 10, "2")]);
+        assert_eq!(&text[1..6], "FünF");
+    }
+
+    #[test]
+    fn ignore_case_lowercase_expansion() {
+        // 'İ' lowercases to "i" and a combining dot, two chars from one
+        let scanner = Scanner::new_ignore_case([("i\u{307}ki", "2"), ("ki", "ki")]);
+        let text = "İKI";
+        assert_eq!(found(&scanner, text), vec![(0, 4, "2"), (2, 4, "ki")]);
+        assert_eq!(&text[0..4], "İKI");
+    }
+}
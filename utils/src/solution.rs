@@ -0,0 +1,119 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::{Options, ParseError};
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/// The value produced by a puzzle part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+    Unsolved,
+    /// The input rules out an answer, with the reason.
+    Failed(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(x) => write!(f, "{}", x),
+            Answer::UInt(x) => write!(f, "{}", x),
+            Answer::Text(x) => write!(f, "{}", x),
+            Answer::Unsolved => write!(f, "unsolved"),
+            Answer::Failed(x) => write!(f, "failed: {}", x),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Int(value.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Int(value)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::UInt(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::UInt(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::UInt(value as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// How a day's report is rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Text,
+    Csv,
+    Json,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(ReportFormat::Text),
+            "csv" => Ok(ReportFormat::Csv),
+            "json" => Ok(ReportFormat::Json),
+            _ => Err(format!("unknown report format {:?}", s)),
+        }
+    }
+}
+
+/// A day's puzzle: parse the input once, then solve each part from it.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parses with extra settings from the command line. Days that take
+    /// none ignore them.
+    fn parse_with(input: &str, _options: &Options) -> Result<Self::Input> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Problems found in an input that parsed but looks suspect.
+    fn warnings(_input: &Self::Input) -> Vec<String> {
+        vec![]
+    }
+
+    /// An item by item breakdown of the input for debugging, if the day has one.
+    fn report(_input: &Self::Input, _format: ReportFormat) -> Option<String> {
+        None
+    }
+}